@@ -0,0 +1,33 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+//
+// Pulls prism's math modules in as-is, any std usage in them fails to compile here
+//
+
+#[path = "../../../src/math"]
+pub mod math {
+    pub mod component;
+
+    pub mod vector;
+    pub mod matrix;
+    pub mod ray;
+    pub mod sdf;
+    pub mod grid;
+    pub mod transform;
+    pub mod stats;
+    pub mod aabb;
+
+    #[cfg(feature = "alloc")]
+    pub mod mesh;
+}
+
+use math::vector::common::*;
+use math::matrix::common::*;
+
+/// Moves a point by a transform
+pub fn transform_point(transform: Matrix4x4, point: Vector3) -> Vector3 {
+    Vector3::from(transform * Vector4::new(point[0], point[1], point[2], 1f32))
+}
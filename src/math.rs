@@ -3,6 +3,17 @@ pub mod component;
 pub mod vector;
 pub mod matrix;
 pub mod ray;
+pub mod sdf;
+pub mod grid;
+pub mod transform;
+pub mod stats;
+pub mod aabb;
+
+// Meshes own their vertices
+#[cfg(feature = "alloc")]
+pub mod mesh;
+
+pub mod interop;
 
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -0,0 +1,62 @@
+//
+// Axis aligned bounding box backed by Vector<f32, 3> aka Vector3
+//
+
+use crate::math::vector::common::Vector3;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[allow(dead_code)]
+pub struct Aabb {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+impl Aabb {
+    #[allow(dead_code)]
+    pub fn new(min: Vector3, max: Vector3) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns a box containing nothing, growing it by any point makes it contain just that point
+    ///
+    /// # Note:
+    ///     `min` is positive infinity and `max` negative infinity until something is added!
+    #[allow(dead_code)]
+    pub fn empty() -> Self {
+        Self { min: Vector3::from_single(f32::INFINITY), max: Vector3::from_single(f32::NEG_INFINITY) }
+    }
+
+    /// Returns the smallest box containing every point, [Aabb::empty] if there are none
+    #[allow(dead_code)]
+    pub fn from_points(points: &[Vector3]) -> Self {
+        points.iter().fold(Self::empty(), |aabb, p| aabb.grow(*p))
+    }
+
+    /// Returns this box extended just enough to contain `p`
+    #[allow(dead_code)]
+    pub fn grow(&self, p: Vector3) -> Self {
+        Self { min: self.min.min(p), max: self.max.max(p) }
+    }
+
+    /// Returns true if `p` is inside or on the surface of this box
+    #[allow(dead_code)]
+    pub fn contains(&self, p: Vector3) -> bool {
+        (0 .. 3).all(|c| p[c] >= self.min[c] && p[c] <= self.max[c])
+    }
+
+    /// Returns the point inside this box closest to `p` (see [Vector3::clamp_to_aabb])
+    #[allow(dead_code)]
+    pub fn clamp_point(&self, p: Vector3) -> Vector3 {
+        p.clamp_to_aabb(self.min, self.max)
+    }
+
+    #[allow(dead_code)]
+    pub fn center(&self) -> Vector3 {
+        (self.min + self.max) * 0.5f32
+    }
+
+    #[allow(dead_code)]
+    pub fn size(&self) -> Vector3 {
+        self.max - self.min
+    }
+}
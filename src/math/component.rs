@@ -1,6 +1,66 @@
-use std::ops::*;
-use std::cmp::*;
-use std::fmt::*;
+use core::ops::*;
+use core::cmp::*;
+use core::fmt::*;
+
+// Without std the float intrinsics come from libm, the std shim mirrors its names
+#[cfg(not(feature = "std"))]
+mod float {
+    pub use libm::*;
+
+    // libm has no integer power, square and multiply so it agrees with std's powi
+    pub fn powif(x: f32, n: i32) -> f32 {
+        let (mut base, mut e, mut r) = (x, n.unsigned_abs(), 1f32);
+
+        while e > 0 {
+            if e & 1 == 1 {
+                r *= base;
+            }
+
+            base *= base;
+            e >>= 1;
+        }
+
+        if n < 0 { 1f32 / r } else { r }
+    }
+
+    pub fn powi(x: f64, n: i32) -> f64 {
+        let (mut base, mut e, mut r) = (x, n.unsigned_abs(), 1f64);
+
+        while e > 0 {
+            if e & 1 == 1 {
+                r *= base;
+            }
+
+            base *= base;
+            e >>= 1;
+        }
+
+        if n < 0 { 1f64 / r } else { r }
+    }
+}
+
+#[cfg(feature = "std")]
+mod float {
+    pub fn sqrtf(x: f32) -> f32 { x.sqrt() }
+    pub fn sinf(x: f32) -> f32 { x.sin() }
+    pub fn cosf(x: f32) -> f32 { x.cos() }
+    pub fn tanf(x: f32) -> f32 { x.tan() }
+    pub fn acosf(x: f32) -> f32 { x.acos() }
+    pub fn floorf(x: f32) -> f32 { x.floor() }
+    pub fn cbrtf(x: f32) -> f32 { x.cbrt() }
+    pub fn powif(x: f32, n: i32) -> f32 { x.powi(n) }
+    pub fn copysignf(x: f32, y: f32) -> f32 { x.copysign(y) }
+
+    pub fn sqrt(x: f64) -> f64 { x.sqrt() }
+    pub fn sin(x: f64) -> f64 { x.sin() }
+    pub fn cos(x: f64) -> f64 { x.cos() }
+    pub fn tan(x: f64) -> f64 { x.tan() }
+    pub fn acos(x: f64) -> f64 { x.acos() }
+    pub fn floor(x: f64) -> f64 { x.floor() }
+    pub fn cbrt(x: f64) -> f64 { x.cbrt() }
+    pub fn powi(x: f64, n: i32) -> f64 { x.powi(n) }
+    pub fn copysign(x: f64, y: f64) -> f64 { x.copysign(y) }
+}
 
 //
 // Delegations (allows us to verify components can work!)
@@ -9,22 +69,46 @@ use std::fmt::*;
 /// Required trait for components!
 pub trait MathDelegate {
     fn sqrt_delegate(&self) -> Self;
+    fn cbrt_delegate(&self) -> Self;
+
+    fn powi_delegate(&self, n: i32) -> Self;
+
+    /// Approximate reciprocal square root, trades precision for speed (see [crate::math::vector::Vector::normalize_fast])
+    fn inv_sqrt_fast_delegate(&self) -> Self;
 
     fn sin_delegate(&self) -> Self;
     fn cos_delegate(&self) -> Self;
     fn tan_delegate(&self) -> Self;
+    fn acos_delegate(&self) -> Self;
+
+    fn floor_delegate(&self) -> Self;
 
     fn abs_delegate(&self) -> Self;
+
+    /// Returns the magnitude of this value with the sign of `sign`
+    fn copysign_delegate(&self, sign: Self) -> Self;
+
+    fn min_delegate(&self, rhs: Self) -> Self;
+    fn max_delegate(&self, rhs: Self) -> Self;
 }
 
 /// Required trait for operations requiring conversions!
 pub trait Constants {
+    #[allow(dead_code)]
     fn rad_to_deg() -> Self;
+    #[allow(dead_code)]
     fn deg_to_rad() -> Self;
 
+    #[allow(dead_code)]
     fn pi() -> Self;
 
     fn get_one() -> Self;
+
+    /// Difference between one and the next representable value, see [f32::EPSILON]
+    #[allow(dead_code)]
+    fn epsilon() -> Self;
+
+    fn from_usize(value: usize) -> Self;
 }
 
 
@@ -36,11 +120,31 @@ pub trait Component:
 Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> +
 AddAssign + SubAssign + MulAssign + DivAssign +
 Neg<Output=Self> +
-PartialEq +
+PartialEq + PartialOrd +
 MathDelegate + Constants +
 Clone + Copy + Default + Display
     where Self: Sized {
 
+    /// Restricts the value to `[lo, hi]`
+    fn clamp(self, lo: Self, hi: Self) -> Self {
+        self.max_delegate(lo).min_delegate(hi)
+    }
+
+    /// Restricts the value to `[0, 1]`
+    fn saturate(self) -> Self {
+        Component::clamp(self, Self::default(), Self::get_one())
+    }
+
+    /// Returns true if the two values are equal to within [Constants::epsilon], relative to their size
+    ///
+    /// # Note:
+    ///     Values closer to zero than one are compared absolutely, as relative error blows up near zero!
+    #[allow(dead_code)]
+    fn approx_eq(self, rhs: Self) -> bool {
+        let scale = self.abs_delegate().max_delegate(rhs.abs_delegate()).max_delegate(Self::get_one());
+
+        (self - rhs).abs_delegate() <= Self::epsilon() * scale
+    }
 }
 
 //
@@ -52,42 +156,90 @@ impl Component for f32 {}
 
 impl MathDelegate for f32 {
     fn sqrt_delegate(&self) -> Self {
-        self.sqrt()
+        float::sqrtf(*self)
+    }
+
+    fn cbrt_delegate(&self) -> Self {
+        float::cbrtf(*self)
+    }
+
+    fn powi_delegate(&self, n: i32) -> Self {
+        float::powif(*self, n)
+    }
+
+    // https://en.wikipedia.org/wiki/Fast_inverse_square_root
+    // Two newton iterations keeps the relative error well under 0.01%
+    fn inv_sqrt_fast_delegate(&self) -> Self {
+        let half = self * 0.5f32;
+        let mut y = f32::from_bits(0x5f3759df - (self.to_bits() >> 1));
+
+        y *= 1.5f32 - half * y * y;
+        y *= 1.5f32 - half * y * y;
+
+        y
     }
     
     fn sin_delegate(&self) -> Self {
-        self.sin()
+        float::sinf(*self)
     }
 
     fn cos_delegate(&self) -> Self {
-        self.cos()
+        float::cosf(*self)
     }
 
     fn tan_delegate(&self) -> Self {
-        self.tan()
+        float::tanf(*self)
+    }
+
+    fn acos_delegate(&self) -> Self {
+        float::acosf(*self)
+    }
+
+    fn floor_delegate(&self) -> Self {
+        float::floorf(*self)
     }
 
     fn abs_delegate(&self) -> Self {
         self.abs()
     }
+
+    fn copysign_delegate(&self, sign: Self) -> Self {
+        float::copysignf(*self, sign)
+    }
+
+    fn min_delegate(&self, rhs: Self) -> Self {
+        self.min(rhs)
+    }
+
+    fn max_delegate(&self, rhs: Self) -> Self {
+        self.max(rhs)
+    }
 }
 
 impl Constants for f32 {
     fn rad_to_deg() -> Self {
-        57.2957795131f32
+        180f32 / core::f32::consts::PI
     }
 
     fn deg_to_rad() -> Self {
-        0.01745329251f32
+        core::f32::consts::PI / 180f32
     }
 
     fn pi() -> Self {
-        std::f32::consts::PI
+        core::f32::consts::PI
     }
 
     fn get_one() -> Self {
         1f32
     }
+
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
+
+    fn from_usize(value: usize) -> Self {
+        value as f32
+    }
 }
 
 // F64
@@ -95,40 +247,86 @@ impl Component for f64 {}
 
 impl MathDelegate for f64 {
     fn sqrt_delegate(&self) -> Self {
-        self.sqrt()
+        float::sqrt(*self)
+    }
+
+    fn cbrt_delegate(&self) -> Self {
+        float::cbrt(*self)
+    }
+
+    fn powi_delegate(&self, n: i32) -> Self {
+        float::powi(*self, n)
+    }
+
+    fn inv_sqrt_fast_delegate(&self) -> Self {
+        let half = self * 0.5f64;
+        let mut y = f64::from_bits(0x5fe6eb50c7b537a9 - (self.to_bits() >> 1));
+
+        y *= 1.5f64 - half * y * y;
+        y *= 1.5f64 - half * y * y;
+
+        y
     }
 
     fn sin_delegate(&self) -> Self {
-        self.sin()
+        float::sin(*self)
     }
 
     fn cos_delegate(&self) -> Self {
-        self.cos()
+        float::cos(*self)
     }
 
     fn tan_delegate(&self) -> Self {
-        self.tan()
+        float::tan(*self)
+    }
+
+    fn acos_delegate(&self) -> Self {
+        float::acos(*self)
+    }
+
+    fn floor_delegate(&self) -> Self {
+        float::floor(*self)
     }
 
     fn abs_delegate(&self) -> Self {
         self.abs()
     }
+
+    fn copysign_delegate(&self, sign: Self) -> Self {
+        float::copysign(*self, sign)
+    }
+
+    fn min_delegate(&self, rhs: Self) -> Self {
+        self.min(rhs)
+    }
+
+    fn max_delegate(&self, rhs: Self) -> Self {
+        self.max(rhs)
+    }
 }
 
 impl Constants for f64 {
     fn rad_to_deg() -> Self {
-        57.2957795131f64
+        180f64 / core::f64::consts::PI
     }
 
     fn deg_to_rad() -> Self {
-        0.01745329251f64
+        core::f64::consts::PI / 180f64
     }
 
     fn pi() -> Self {
-        std::f64::consts::PI
+        core::f64::consts::PI
     }
 
     fn get_one() -> Self {
         1f64
     }
+
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+
+    fn from_usize(value: usize) -> Self {
+        value as f64
+    }
 }
\ No newline at end of file
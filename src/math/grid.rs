@@ -0,0 +1,81 @@
+//
+// Uniform grid helpers
+//
+
+use crate::math::component::MathDelegate;
+use crate::math::ray::Ray3D;
+
+///
+/// Iterator over the cells of a uniform grid a ray passes through, in order
+///
+/// Implements "A Fast Voxel Traversal Algorithm for Ray Tracing" by Amanatides and Woo
+///
+#[allow(dead_code)]
+pub struct GridTraversal {
+    cell: [i32; 3],
+    step: [i32; 3],
+
+    /// Distance along the ray until the next cell boundary on each axis
+    t_max: [f32; 3],
+
+    /// Distance along the ray between cell boundaries on each axis
+    t_delta: [f32; 3],
+}
+
+impl Iterator for GridTraversal {
+    type Item = (i32, i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = (self.cell[0], self.cell[1], self.cell[2]);
+
+        // Step along whichever axis reaches its next boundary first
+        let mut axis = 0;
+
+        for c in 1 .. 3 {
+            if self.t_max[c] < self.t_max[axis] {
+                axis = c;
+            }
+        }
+
+        self.cell[axis] += self.step[axis];
+        self.t_max[axis] += self.t_delta[axis];
+
+        Some(current)
+    }
+}
+
+/// Returns the cells of a grid with cubic cells of `cell_size` that the ray visits, starting at the cell containing the origin
+///
+/// # Note:
+///     The iterator never ends, limit it with [Iterator::take] or [Iterator::take_while]!
+///     Panics if `cell_size` isn't positive (zero, negative and NaN sizes have no cells to visit)
+#[allow(dead_code)]
+pub fn traverse(ray: &Ray3D, cell_size: f32) -> GridTraversal {
+    assert!(cell_size > 0f32, "cell_size must be positive, got {}", cell_size);
+
+    let mut traversal = GridTraversal {
+        cell: [0; 3],
+        step: [0; 3],
+        t_max: [f32::INFINITY; 3],
+        t_delta: [f32::INFINITY; 3],
+    };
+
+    for c in 0 .. 3 {
+        let origin = ray.origin[c] / cell_size;
+        let direction = ray.direction[c];
+
+        traversal.cell[c] = origin.floor_delegate() as i32;
+
+        if direction > 0f32 {
+            traversal.step[c] = 1;
+            traversal.t_max[c] = (origin.floor_delegate() + 1f32 - origin) * cell_size / direction;
+            traversal.t_delta[c] = cell_size / direction;
+        } else if direction < 0f32 {
+            traversal.step[c] = -1;
+            traversal.t_max[c] = (origin - origin.floor_delegate()) * cell_size / -direction;
+            traversal.t_delta[c] = cell_size / -direction;
+        }
+    }
+
+    traversal
+}
@@ -0,0 +1,10 @@
+//
+// Conversions between PRISM math types and other math libraries
+// Each library is gated behind a feature of the same name
+//
+
+#[cfg(feature = "glam")]
+pub mod glam;
+
+#[cfg(feature = "mint")]
+pub mod mint;
@@ -0,0 +1,41 @@
+//
+// glam interop (requires the "glam" feature)
+//
+// PRISM matrices are row-major while glam matrices are column-major
+// Conversions transpose between the two so that the same transform is represented on both sides
+//
+
+use crate::math::vector::common::*;
+use crate::math::matrix::common::*;
+
+macro_rules! vector_glam {
+    ($vector:ty, $glam:ty) => {
+        impl From<$glam> for $vector {
+            fn from(rhs: $glam) -> Self {
+                Self::from_array(rhs.to_array())
+            }
+        }
+
+        impl From<$vector> for $glam {
+            fn from(rhs: $vector) -> Self {
+                <$glam>::from_array(rhs.data)
+            }
+        }
+    };
+}
+
+vector_glam!(Vector2, ::glam::Vec2);
+vector_glam!(Vector3, ::glam::Vec3);
+vector_glam!(Vector4, ::glam::Vec4);
+
+impl From<::glam::Mat4> for Matrix4x4 {
+    fn from(rhs: ::glam::Mat4) -> Self {
+        Matrix4x4::from_array(rhs.to_cols_array_2d()).transpose()
+    }
+}
+
+impl From<Matrix4x4> for ::glam::Mat4 {
+    fn from(rhs: Matrix4x4) -> Self {
+        ::glam::Mat4::from_cols_array_2d(&rhs.transpose().data)
+    }
+}
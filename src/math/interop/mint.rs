@@ -0,0 +1,43 @@
+//
+// mint interop (requires the "mint" feature)
+//
+// PRISM matrices are row-major, mint::ColumnMatrix4 is column-major
+// Conversions transpose between the two so that the same transform is represented on both sides
+//
+
+use crate::math::vector::common::*;
+use crate::math::matrix::common::*;
+
+macro_rules! vector_mint {
+    ($vector:ty, $mint:ty) => {
+        impl From<$mint> for $vector {
+            fn from(rhs: $mint) -> Self {
+                Self::from_array(rhs.into())
+            }
+        }
+
+        impl From<$vector> for $mint {
+            fn from(rhs: $vector) -> Self {
+                <$mint>::from(rhs.data)
+            }
+        }
+    };
+}
+
+vector_mint!(Vector2, ::mint::Vector2<f32>);
+vector_mint!(Vector3, ::mint::Vector3<f32>);
+vector_mint!(Vector4, ::mint::Vector4<f32>);
+
+impl From<::mint::ColumnMatrix4<f32>> for Matrix4x4 {
+    fn from(rhs: ::mint::ColumnMatrix4<f32>) -> Self {
+        let columns: [[f32; 4]; 4] = rhs.into();
+
+        Matrix4x4::from_array(columns).transpose()
+    }
+}
+
+impl From<Matrix4x4> for ::mint::ColumnMatrix4<f32> {
+    fn from(rhs: Matrix4x4) -> Self {
+        ::mint::ColumnMatrix4::from(rhs.transpose().data)
+    }
+}
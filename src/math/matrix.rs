@@ -3,9 +3,10 @@
 
 use super::component::Component;
 
-use std::ops::*;
-use std::cmp::*;
-use std::fmt::*;
+use core::ops::*;
+use core::cmp::*;
+use core::fmt::*;
+use core::str::FromStr;
 use crate::math::vector::Vector;
 
 /// [Matrix] and [Vector] are very closely related!
@@ -14,7 +15,8 @@ use crate::math::vector::Vector;
 /// The underlying implementation of a [Matrix] is similar to a [Vector] except a 2D array instead of a 1D array
 ///
 /// # Note:
-///     Generic [Matrix] currently lacks determinant() and inverse(), you'll find it on the common types instead!
+///     Generic [Matrix] currently lacks inverse(), you'll find it on the common types instead!
+///     The common types also have a faster closed form determinant() than [Matrix::determinant_generic]
 #[derive(Copy, Clone)]
 #[repr(C)]
 pub struct Matrix<T: Component, const WIDTH: usize, const HEIGHT: usize> {
@@ -27,24 +29,125 @@ impl<T: Component, const WIDTH: usize, const HEIGHT: usize> Matrix<T, WIDTH, HEI
         Self { data: array }
     }
 
+    /// Creates a new [Matrix] where each [Vector] is a row
+    pub fn from_rows(rows: [Vector<T, WIDTH>; HEIGHT]) -> Self {
+        Self { data: rows.map(|r| r.data) }
+    }
+
+    /// Creates a new [Matrix] where each [Vector] is a column
+    pub fn from_columns(columns: [Vector<T, HEIGHT>; WIDTH]) -> Self {
+        let mut m = Self::default();
+
+        for x in 0 .. WIDTH {
+            for y in 0 .. HEIGHT {
+                m[y][x] = columns[x][y];
+            }
+        }
+
+        m
+    }
+
+    /// Creates a new [Matrix] by calling `f` with the `(row, col)` of every element
+    pub fn from_fn(f: impl Fn(usize, usize) -> T) -> Self {
+        Self { data: core::array::from_fn(|y| core::array::from_fn(|x| f(y, x))) }
+    }
+
+    /// Returns a copy of this [Matrix] with `f` applied to every element
+    pub fn map(&self, f: impl Fn(T) -> T) -> Self {
+        Self { data: self.data.map(|row| row.map(&f)) }
+    }
+
     /// Provides an identity matrix (this works best with evenly shaped [Matrix] types!)
+    ///
+    /// For oddly shaped [Matrix] types the first `min(WIDTH, HEIGHT)` diagonal elements are one, everything else is zero
     pub fn identity() -> Self {
         let mut array = [[T::default(); WIDTH]; HEIGHT];
 
         // Only diagonals are populated, therefore X and Y are the same!
-        // We use WIDTH as our frame of reference
-        for c in 0 .. WIDTH {
-            // Depth check
-            if c >= HEIGHT {
-                break;
-            }
-
-            array[c][c] = T::get_one();
+        // Stop at whichever of WIDTH or HEIGHT runs out first
+        for (c, row) in array.iter_mut().enumerate().take(WIDTH) {
+            row[c] = T::get_one();
         }
 
         Self { data: array }
     }
 
+    /// Returns the element at the given row and column, or [None] if it's out of bounds
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.data.get(row)?.get(col)
+    }
+
+    /// Returns the element at the given row and column mutably, or [None] if it's out of bounds
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.data.get_mut(row)?.get_mut(col)
+    }
+
+    /// Returns the row at index `row` as a [Vector]
+    pub fn row(&self, row: usize) -> Vector<T, WIDTH> {
+        Vector::from_array(self[row])
+    }
+
+    /// Returns the column at index `col` as a [Vector]
+    pub fn col(&self, col: usize) -> Vector<T, HEIGHT> {
+        Vector::from_array(self.data.map(|r| r[col]))
+    }
+
+    /// Overwrites the row at index `row`
+    pub fn set_row(&mut self, row: usize, v: Vector<T, WIDTH>) {
+        self[row] = v.data;
+    }
+
+    /// Overwrites the column at index `col`
+    pub fn set_col(&mut self, col: usize, v: Vector<T, HEIGHT>) {
+        for y in 0 .. HEIGHT {
+            self[y][col] = v[y];
+        }
+    }
+
+    /// Returns all `WIDTH * HEIGHT` elements as one contiguous slice
+    ///
+    /// # Note:
+    ///     Elements are in row-major order, the first row is followed by the second row and so on
+    pub fn as_flat_slice(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+
+    /// Returns a pointer to the first element, elements follow in row-major order (see [Matrix::as_flat_slice])
+    pub fn as_ptr(&self) -> *const T {
+        self.data.as_ptr() as *const T
+    }
+
+    /// Iterates over the rows of this [Matrix] from top to bottom
+    pub fn iter_rows(&self) -> impl Iterator<Item=Vector<T, WIDTH>> + '_ {
+        self.data.iter().map(|row| Vector::from_array(*row))
+    }
+
+    /// Iterates over every element of this [Matrix] in row-major order (see [Matrix::as_flat_slice])
+    pub fn iter_elements(&self) -> impl Iterator<Item=T> + '_ {
+        self.as_flat_slice().iter().copied()
+    }
+
+    /// Returns true if every element of this [Matrix] is within `epsilon` of the matching element in another
+    pub fn approx_eq(&self, rhs: Self, epsilon: T) -> bool {
+        self.iter_elements().zip(rhs.iter_elements()).all(|(a, b)| (a - b).abs_delegate() <= epsilon)
+    }
+
+    /// Returns the element-wise (Hadamard) product of this [Matrix] and another
+    ///
+    /// # Note:
+    ///     This is NOT the matrix product, use `*` for that!
+    pub fn hadamard(&self, rhs: Self) -> Self {
+        let mut m = *self;
+
+        for y in 0 .. HEIGHT {
+            for x in 0 .. WIDTH {
+                m[y][x] *= rhs[y][x];
+            }
+        }
+
+        m
+    }
+
     /// Transposes the matrix (for oddly shaped [Matrix] types, it will flip [WIDTH] and [HEIGHT]!)
     pub fn transpose(&self) -> Matrix<T, HEIGHT, WIDTH> {
         let mut m = Matrix::<T, HEIGHT, WIDTH>::default();
@@ -59,6 +162,167 @@ impl<T: Component, const WIDTH: usize, const HEIGHT: usize> Matrix<T, WIDTH, HEI
     }
 }
 
+impl<T: Component, const SIZE: usize> Matrix<T, SIZE, SIZE> {
+    /// Returns the determinant of a square [Matrix] of any size using Gaussian elimination with partial pivoting
+    ///
+    /// # Note:
+    ///     Prefer determinant() on the common types when the size is known, it's exact and faster
+    pub fn determinant_generic(&self) -> T {
+        let mut m = *self;
+        let mut det = T::get_one();
+
+        for c in 0 .. SIZE {
+            // Pick the largest remaining pivot in this column to keep the division stable
+            let mut pivot = c;
+
+            for y in c + 1 .. SIZE {
+                if m[y][c].abs_delegate() > m[pivot][c].abs_delegate() {
+                    pivot = y;
+                }
+            }
+
+            if m[pivot][c] == T::default() {
+                return T::default();
+            }
+
+            if pivot != c {
+                m.data.swap(pivot, c);
+                det = -det;
+            }
+
+            det *= m[c][c];
+
+            for y in c + 1 .. SIZE {
+                let f = m[y][c] / m[c][c];
+
+                for x in c .. SIZE {
+                    let v = m[c][x];
+                    m[y][x] -= f * v;
+                }
+            }
+        }
+
+        det
+    }
+
+    /// Estimates the condition number of this [Matrix], the ratio of its largest to smallest singular value
+    ///
+    /// Close to 1 is well behaved (rotations are exactly 1), large values mean inverting it amplifies error a lot
+    /// Singular matrices return infinity
+    ///
+    /// # Note:
+    ///     The singular values come from power iteration, so this is an estimate rather than exact
+    pub fn condition_number_estimate(&self) -> T {
+        let inverse = match self.inverse_generic() {
+            Some(inverse) => inverse,
+            None => return T::get_one() / T::default(),
+        };
+
+        // The squared singular values are the eigenvalues of AᵀA, and the smallest one of A is the largest one of A⁻¹
+        let largest = (self.transpose() * *self).largest_eigenvalue();
+        let smallest = (inverse.transpose() * inverse).largest_eigenvalue();
+
+        (largest * smallest).sqrt_delegate()
+    }
+
+    /// Returns the inverse of a square [Matrix] of any size using Gauss-Jordan elimination, [None] if it's singular
+    fn inverse_generic(&self) -> Option<Self> {
+        let mut m = *self;
+        let mut inverse = Self::identity();
+
+        for c in 0 .. SIZE {
+            let mut pivot = c;
+
+            for y in c + 1 .. SIZE {
+                if m[y][c].abs_delegate() > m[pivot][c].abs_delegate() {
+                    pivot = y;
+                }
+            }
+
+            if m[pivot][c] == T::default() {
+                return None;
+            }
+
+            m.data.swap(pivot, c);
+            inverse.data.swap(pivot, c);
+
+            let p = m[c][c];
+
+            for x in 0 .. SIZE {
+                m[c][x] /= p;
+                inverse[c][x] /= p;
+            }
+
+            for y in 0 .. SIZE {
+                if y == c {
+                    continue;
+                }
+
+                let f = m[y][c];
+
+                for x in 0 .. SIZE {
+                    let (a, b) = (m[c][x], inverse[c][x]);
+
+                    m[y][x] -= f * a;
+                    inverse[y][x] -= f * b;
+                }
+            }
+        }
+
+        Some(inverse)
+    }
+
+    /// Largest eigenvalue of a symmetric positive semi-definite [Matrix] by power iteration
+    fn largest_eigenvalue(&self) -> T {
+        let apply = |v: Vector<T, SIZE>| {
+            let mut w = Vector::<T, SIZE>::default();
+
+            for y in 0 .. SIZE {
+                w[y] = self.row(y).dot(v);
+            }
+
+            w
+        };
+
+        // Power iteration never leaves an eigenvector it starts on, so start from an uneven ramp (1, 2, 3, ...)
+        // A uniform start sits exactly on an eigenvector of any symmetric matrix whose rows have equal sums
+        let mut v = Vector::<T, SIZE>::default();
+
+        for c in 0 .. SIZE {
+            v[c] = T::from_usize(c + 1);
+        }
+
+        v = v.normalize();
+
+        // If the ramp happens to land in the null space, restart from the basis vectors until one doesn't
+        let mut c = 0;
+
+        while c < SIZE && apply(v).magnitude() == T::default() {
+            v = Vector::<T, SIZE>::default();
+            v[c] = T::get_one();
+            c += 1;
+        }
+
+        let mut lambda = T::default();
+
+        for _ in 0 .. 64 {
+            let w = apply(v);
+
+            lambda = v.dot(w);
+
+            let length = w.magnitude();
+
+            if length == T::default() {
+                break;
+            }
+
+            v = w / length;
+        }
+
+        lambda
+    }
+}
+
 //
 // Deref
 //
@@ -80,7 +344,7 @@ impl<T: Component, const WIDTH: usize, const HEIGHT: usize> DerefMut for Matrix<
 // Formatters
 //
 impl<T: Component, const WIDTH: usize, const HEIGHT: usize> Display for Matrix<T, WIDTH, HEIGHT> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         for y in 0 .. HEIGHT {
             if y != 0 {
                 writeln!(f).expect("Failed to write!");
@@ -103,6 +367,69 @@ impl<T: Component, const WIDTH: usize, const HEIGHT: usize> Display for Matrix<T
     }
 }
 
+//
+// Parsing
+//
+
+/// Why a [Matrix] couldn't be parsed from text (see [Matrix::from_str])
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseMatrixError {
+    /// The text didn't contain exactly `HEIGHT` rows
+    RowCount,
+
+    /// The row isn't wrapped in `[` and `]`
+    Brackets { row: usize },
+
+    /// The row doesn't contain exactly `WIDTH` components
+    ColumnCount { row: usize },
+
+    /// The component couldn't be parsed as a number
+    Component { row: usize, col: usize },
+}
+
+impl Display for ParseMatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::RowCount => write!(f, "wrong number of rows"),
+            Self::Brackets { row } => write!(f, "row {} isn't wrapped in brackets", row),
+            Self::ColumnCount { row } => write!(f, "wrong number of components in row {}", row),
+            Self::Component { row, col } => write!(f, "invalid component at row {}, column {}", row, col),
+        }
+    }
+}
+
+/// Parses the format written by [Display], one `[a, b, c]` row per line
+impl<T: Component + FromStr, const WIDTH: usize, const HEIGHT: usize> FromStr for Matrix<T, WIDTH, HEIGHT> {
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        let mut m = Self::default();
+        let mut rows = s.lines().map(str::trim).filter(|l| !l.is_empty());
+
+        for y in 0 .. HEIGHT {
+            let row = rows.next().ok_or(ParseMatrixError::RowCount)?;
+            let row = row.strip_prefix('[').and_then(|r| r.strip_suffix(']')).ok_or(ParseMatrixError::Brackets { row: y })?;
+
+            let mut components = row.split(',').map(str::trim);
+
+            for x in 0 .. WIDTH {
+                let c = components.next().ok_or(ParseMatrixError::ColumnCount { row: y })?;
+                m[y][x] = c.parse().map_err(|_| ParseMatrixError::Component { row: y, col: x })?;
+            }
+
+            if components.next().is_some() {
+                return Err(ParseMatrixError::ColumnCount { row: y });
+            }
+        }
+
+        if rows.next().is_some() {
+            return Err(ParseMatrixError::RowCount);
+        }
+
+        Ok(m)
+    }
+}
+
 //
 // Default
 //
@@ -192,7 +519,7 @@ impl<T: Component, const WIDTH: usize, const HEIGHT: usize> Mul<Self> for Matrix
 /// Implementations of Matrix inverse are from https://github.com/g-truc/glm/blob/master/glm/detail/func_matrix.inl
 pub mod common {
     use crate::math::vector::Vector;
-    use crate::{Vector3, Vector4};
+    use crate::math::vector::common::{Vector3, Vector4, Quaternion};
     use super::*;
 
     /// Matrix 2x2
@@ -228,6 +555,11 @@ pub mod common {
             self[2][0] * (self[0][1] * self[1][2] - self[1][1] * self[0][2])
         }
 
+        /// Returns true if the basis formed by the rows of this [Matrix] is right handed (positive determinant)
+        pub fn is_right_handed(&self) -> bool {
+            self.determinant() > T::default()
+        }
+
         pub fn inverse(&self) -> Self {
             let mut i = Self::default();
             let d = T::get_one() / self.determinant();
@@ -246,9 +578,54 @@ pub mod common {
         }
     }
 
+    /// The order euler rotations are composed in
+    ///
+    /// Named by the order the per axis matrices are multiplied, [EulerOrder::XYZ] is `rotate_x * rotate_y * rotate_z`
+    /// Vectors are multiplied on the right, so the last named axis is applied to a vector first!
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum EulerOrder {
+        XYZ,
+        XZY,
+        YXZ,
+        YZX,
+        ZXY,
+        ZYX,
+    }
+
     /// Matrix 4x4
     pub type Matrix4x4 = Matrix<f32, 4, 4>;
 
+    impl Matrix4x4 {
+        /// Returns the rotation matrix of a [Quaternion] (see [Quaternion::to_matrix])
+        pub fn from_quaternion(q: Quaternion) -> Self {
+            q.to_matrix()
+        }
+
+        /// Returns the elements in column-major order, ready to upload to OpenGL without transposing
+        pub fn to_gl_array(self) -> [f32; 16] {
+            let mut array = [0f32; 16];
+
+            for y in 0 .. 4 {
+                for x in 0 .. 4 {
+                    array[x * 4 + y] = self[y][x];
+                }
+            }
+
+            array
+        }
+
+        /// Maps a point in normalized device coordinates back to world space, including the perspective divide
+        ///
+        /// # Note:
+        ///     This matrix must be the inverse of the view projection matrix, invert it once and reuse it when unprojecting many points!
+        pub fn unproject(&self, ndc: Vector3) -> Vector3 {
+            let p = *self * Vector4::new(ndc[0], ndc[1], ndc[2], 1f32);
+
+            Vector3::from(p / p[3])
+        }
+    }
+
     impl<T: Component> Matrix<T, 4, 4> {
         pub fn from_vectors(r0: Vector<T, 4>, r1: Vector<T, 4>, r2: Vector<T, 4>, r3: Vector<T, 4>) -> Self {
             Self { data: [*r0, *r1, *r2, *r3] }
@@ -312,19 +689,114 @@ pub mod common {
             i * d
         }
 
+        /// Right handed perspective projection with a GL style `[-1, 1]` depth range (see [Matrix::perspective_rh_no])
         pub fn perspective(fov_y: T, aspect: T, z_near: T, z_far: T) -> Self {
+            Self::perspective_rh_no(fov_y, aspect, z_near, z_far)
+        }
+
+        /// Same as [Matrix::perspective] but with independent horizontal and vertical fields of view, instead of deriving one from the aspect ratio
+        pub fn perspective_fov(fov_x: T, fov_y: T, z_near: T, z_far: T) -> Self {
             let one = T::get_one();
             let two = one + one;
 
-            let half_fov = (fov_y / two);
+            let mut m = Self::perspective_rh_no(fov_y, one, z_near, z_far);
+
+            m[0][0] = one / (fov_x / two).tan_delegate();
+
+            m
+        }
+
+        /// Shared X and Y scaling of the perspective projections
+        fn perspective_base(fov_y: T, aspect: T) -> Self {
+            let one = T::get_one();
+            let two = one + one;
+
+            let focal = one / (fov_y / two).tan_delegate();
 
             let mut m = Self::default();
 
-            m[0][0] = one / (aspect * half_fov);
-            m[1][1] = one / (half_fov);
+            m[0][0] = focal / aspect;
+            m[1][1] = focal;
+
+            m
+        }
+
+        /// Right handed perspective projection mapping depth into `[-1, 1]` (OpenGL)
+        ///
+        /// The camera looks down -Z, the near plane maps to -1 and the far plane to 1
+        pub fn perspective_rh_no(fov_y: T, aspect: T, z_near: T, z_far: T) -> Self {
+            let one = T::get_one();
+            let two = one + one;
+
+            let mut m = Self::perspective_base(fov_y, aspect);
+
             m[2][2] = -(z_far + z_near) / (z_far - z_near);
-            m[2][3] = -one;
-            m[3][2] = -(two * z_far * z_near) / (z_far - z_near);
+            m[2][3] = -(two * z_far * z_near) / (z_far - z_near);
+            m[3][2] = -one;
+
+            m
+        }
+
+        /// Right handed perspective projection mapping depth into `[0, 1]` (Vulkan, WGPU and D3D with a right handed view)
+        ///
+        /// The camera looks down -Z, the near plane maps to 0 and the far plane to 1
+        pub fn perspective_rh_zo(fov_y: T, aspect: T, z_near: T, z_far: T) -> Self {
+            let one = T::get_one();
+
+            let mut m = Self::perspective_base(fov_y, aspect);
+
+            m[2][2] = z_far / (z_near - z_far);
+            m[2][3] = -(z_far * z_near) / (z_far - z_near);
+            m[3][2] = -one;
+
+            m
+        }
+
+        /// Right handed perspective projection with reversed `[0, 1]` depth, the near plane maps to 1 and the far plane to 0
+        ///
+        /// Floats are densest near zero, reversing the depth spends that precision on distant geometry where it's needed most
+        ///
+        /// # Note:
+        ///     Pair this with a greater-than depth test and clear depth to 0!
+        pub fn perspective_reverse_z(fov_y: T, aspect: T, z_near: T, z_far: T) -> Self {
+            let one = T::get_one();
+
+            let mut m = Self::perspective_base(fov_y, aspect);
+
+            m[2][2] = z_near / (z_far - z_near);
+            m[2][3] = (z_far * z_near) / (z_far - z_near);
+            m[3][2] = -one;
+
+            m
+        }
+
+        /// Left handed perspective projection mapping depth into `[-1, 1]`
+        ///
+        /// The camera looks down +Z, the near plane maps to -1 and the far plane to 1
+        pub fn perspective_lh_no(fov_y: T, aspect: T, z_near: T, z_far: T) -> Self {
+            let one = T::get_one();
+            let two = one + one;
+
+            let mut m = Self::perspective_base(fov_y, aspect);
+
+            m[2][2] = (z_far + z_near) / (z_far - z_near);
+            m[2][3] = -(two * z_far * z_near) / (z_far - z_near);
+            m[3][2] = one;
+
+            m
+        }
+
+        /// Left handed perspective projection mapping depth into `[0, 1]` (D3D)
+        ///
+        /// The camera looks down +Z, the near plane maps to 0 and the far plane to 1
+        pub fn perspective_lh_zo(fov_y: T, aspect: T, z_near: T, z_far: T) -> Self {
+            let one = T::get_one();
+
+            let mut m = Self::perspective_base(fov_y, aspect);
+
+            m[2][2] = z_far / (z_far - z_near);
+            m[2][3] = -(z_far * z_near) / (z_far - z_near);
+            m[3][2] = one;
 
             m
         }
@@ -332,7 +804,9 @@ pub mod common {
         pub fn translate(translation: Vector<T, 3>) -> Self {
             let mut m = Self::identity();
 
-            m[2] = [translation[0], translation[1], translation[2], T::get_one()];
+            m[0][3] = translation[0];
+            m[1][3] = translation[1];
+            m[2][3] = translation[2];
 
             m
         }
@@ -364,24 +838,126 @@ pub mod common {
             m
         }
 
+        /// Returns the rotation of `angle` radians around `axis` (Rodrigues' rotation formula)
+        ///
+        /// # Note:
+        ///     `axis` is normalized internally, it only needs to point the right way
+        pub fn rotate_axis(axis: Vector<T, 3>, angle: T) -> Self {
+            let k = axis.normalize();
+            let (x, y, z) = (k[0], k[1], k[2]);
+
+            let c = angle.cos_delegate();
+            let s = angle.sin_delegate();
+            let t = T::get_one() - c;
+
+            let mut m = Self::identity();
+
+            m[0] = [c + x * x * t, x * y * t - z * s, x * z * t + y * s, T::default()];
+            m[1] = [x * y * t + z * s, c + y * y * t, y * z * t - x * s, T::default()];
+            m[2] = [x * z * t - y * s, y * z * t + x * s, c + z * z * t, T::default()];
+
+            m
+        }
+
+        /// Returns the rotation for the given euler angles in [EulerOrder::XYZ] order
         pub fn rotation(euler: Vector<T, 3>) -> Self {
-            Self::rotate_x(euler[0]) * Self::rotate_y(euler[1]) * Self::rotate_z(euler[2])
+            Self::rotation_order(euler, EulerOrder::XYZ)
         }
 
-        pub fn look_at(direction: Vector<T, 3>) -> Self {
-            let up = Vector::<T, 3>::new(T::default(), -T::get_one(), T::default());
+        /// Returns the rotation for the given euler angles, composing the per axis rotations in the given order
+        pub fn rotation_order(euler: Vector<T, 3>, order: EulerOrder) -> Self {
+            let x = Self::rotate_x(euler[0]);
+            let y = Self::rotate_y(euler[1]);
+            let z = Self::rotate_z(euler[2]);
+
+            match order {
+                EulerOrder::XYZ => x * y * z,
+                EulerOrder::XZY => x * z * y,
+                EulerOrder::YXZ => y * x * z,
+                EulerOrder::YZX => y * z * x,
+                EulerOrder::ZXY => z * x * y,
+                EulerOrder::ZYX => z * y * x,
+            }
+        }
 
-            let r_right = direction.cross(up).normalize();
-            let r_up = direction.cross(r_right).normalize();
+        /// Right handed view matrix for a camera at `eye` looking along `forward`
+        ///
+        /// The camera looks down its local -Z axis, the third row is the negated (normalized) forward direction
+        pub fn look_to(eye: Vector<T, 3>, forward: Vector<T, 3>, up: Vector<T, 3>) -> Self {
+            let f = forward.normalize();
+            let r = f.cross(up).normalize();
+            let u = r.cross(f);
 
             let mut m = Self::identity();
 
-            m[0] = [r_right[0], r_right[1], r_right[2], T::default()];
-            m[1] = [r_up[0], r_up[1], r_up[2], T::default()];
-            m[2] = [direction[0], direction[1], direction[2], T::default()];
+            m.set_row(0, Vector::<T, 4>::new(r[0], r[1], r[2], -r.dot(eye)));
+            m.set_row(1, Vector::<T, 4>::new(u[0], u[1], u[2], -u.dot(eye)));
+            m.set_row(2, Vector::<T, 4>::new(-f[0], -f[1], -f[2], f.dot(eye)));
 
             m
         }
+
+        /// Right handed view matrix for a camera at `eye` looking at `target` (see [Matrix::look_to])
+        pub fn look_at(eye: Vector<T, 3>, target: Vector<T, 3>, up: Vector<T, 3>) -> Self {
+            Self::look_to(eye, target - eye, up)
+        }
+
+        /// Local to world transform placing a sprite at `position` with its local +Z axis facing `camera_pos`
+        ///
+        /// This is the inverse of [Matrix::look_to] from the sprite away from the camera, local +X is right and +Y is up as seen by the camera
+        pub fn billboard(position: Vector<T, 3>, camera_pos: Vector<T, 3>, up: Vector<T, 3>) -> Self {
+            // The rotation is orthonormal, transposing it is the same as inverting it
+            let mut m = Self::look_to(Vector::default(), position - camera_pos, up).transpose();
+            m.set_col(3, Vector::<T, 4>::new(position[0], position[1], position[2], T::get_one()));
+
+            m
+        }
+    }
+
+    /// [Matrix4x4] that remembers its inverse, for matrices that are inverted far more often than they change
+    ///
+    /// The inverse is computed on the first call to [CachedInverse::inverse] and thrown away whenever the matrix is mutated
+    pub struct CachedInverse {
+        matrix: Matrix4x4,
+        inverse: core::cell::Cell<Option<Matrix4x4>>,
+    }
+
+    impl CachedInverse {
+        pub fn new(matrix: Matrix4x4) -> Self {
+            Self { matrix, inverse: core::cell::Cell::new(None) }
+        }
+
+        pub fn matrix(&self) -> Matrix4x4 {
+            self.matrix
+        }
+
+        /// Returns the matrix for editing, the cached inverse is discarded
+        pub fn matrix_mut(&mut self) -> &mut Matrix4x4 {
+            self.inverse.set(None);
+            &mut self.matrix
+        }
+
+        pub fn set(&mut self, matrix: Matrix4x4) {
+            *self.matrix_mut() = matrix;
+        }
+
+        /// Returns the inverse of the matrix, only computing it if it isn't cached already
+        pub fn inverse(&self) -> Matrix4x4 {
+            match self.inverse.get() {
+                Some(inverse) => inverse,
+                None => {
+                    let inverse = self.matrix.inverse();
+                    self.inverse.set(Some(inverse));
+
+                    inverse
+                }
+            }
+        }
+
+        /// Returns true if the next call to [CachedInverse::inverse] won't have to compute anything
+        pub fn is_cached(&self) -> bool {
+            self.inverse.get().is_some()
+        }
     }
 
     /// Matrix * Vector
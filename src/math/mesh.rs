@@ -0,0 +1,57 @@
+//
+// Indexed triangle meshes
+//
+
+use alloc::vec::Vec;
+
+use crate::math::vector::common::Vector3;
+use crate::math::aabb::Aabb;
+
+/// Indices of the three vertices making up a triangle, counter clockwise when facing the front
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Triangle {
+    pub indices: [u32; 3],
+}
+
+#[allow(dead_code)]
+pub struct Mesh {
+    pub vertices: Vec<Vector3>,
+    triangles: Vec<Triangle>,
+}
+
+impl Mesh {
+    /// Creates a [Mesh] where every 3 `indices` make up a triangle, any leftover indices are ignored
+    ///
+    /// # Note:
+    ///     Panics if an index is out of bounds of `vertices`!
+    #[allow(dead_code)]
+    pub fn new(vertices: Vec<Vector3>, indices: &[u32]) -> Self {
+        let triangles: Vec<Triangle> = indices.chunks_exact(3)
+            .map(|i| Triangle { indices: [i[0], i[1], i[2]] })
+            .collect();
+
+        assert!(indices.iter().all(|i| (*i as usize) < vertices.len()), "Mesh index out of bounds!");
+
+        Self { vertices, triangles }
+    }
+
+    #[allow(dead_code)]
+    pub fn triangles(&self) -> &[Triangle] {
+        &self.triangles
+    }
+
+    /// Returns the corners of a triangle of this [Mesh]
+    #[allow(dead_code)]
+    pub fn triangle_vertices(&self, triangle: Triangle) -> (Vector3, Vector3, Vector3) {
+        let [a, b, c] = triangle.indices.map(|i| self.vertices[i as usize]);
+
+        (a, b, c)
+    }
+
+    /// Returns the smallest box containing every vertex, [Aabb::empty] if there are none
+    #[allow(dead_code)]
+    pub fn bounds(&self) -> Aabb {
+        Aabb::from_points(&self.vertices)
+    }
+}
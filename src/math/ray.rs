@@ -5,8 +5,16 @@
 // 3D Ray type backed by Vector<f32, 3> aka Vector3
 //
 
-use crate::math::vector::common::Vector3;
+use crate::math::vector::common::{Vector3, Vector4};
+use crate::math::matrix::common::Matrix4x4;
 
+/// A half line starting at `origin` heading along `direction`
+///
+/// # Note:
+///     `direction` isn't required to be unit length, but every `t` this type hands out ([Ray3D::intersect_triangle], [Ray3D::intersect_mesh],
+///     [Ray3D::closest_point], [Ray3D::closest_approach] and [crate::math::grid::traverse]) is in multiples of it
+///     Those `t` values are only world space distances if the direction is normalized, see [Ray3D::new_normalized]
+#[derive(Copy, Clone)]
 pub struct Ray3D {
     pub origin: Vector3,
     pub direction: Vector3
@@ -14,12 +22,98 @@ pub struct Ray3D {
 
 const EPSILON : f32 = 0.0000001f32;
 
+/// Where a [Ray3D] hit a triangle
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TriangleHit {
+    /// Barycentric weight of the second vertex
+    pub u: f32,
+
+    /// Barycentric weight of the third vertex
+    pub v: f32,
+
+    /// Distance along the ray (in multiples of the direction)
+    pub t: f32,
+}
+
 impl Ray3D {
     pub fn new(origin: Vector3, direction: Vector3) -> Self {
         Self { origin, direction }
     }
 
-    pub fn intersect_triangle(self, (p1, p2, p3) : (Vector3, Vector3, Vector3)) -> Option<(f32, f32, f32)> {
+    /// Same as [Ray3D::new] but normalizes the direction, so any `t` along the ray is a distance
+    pub fn new_normalized(origin: Vector3, direction: Vector3) -> Self {
+        Self { origin, direction: direction.normalize() }
+    }
+
+    /// Returns the point on this [Ray3D] closest to `p` as `(t, point)`
+    ///
+    /// # Note:
+    ///     Points behind the origin snap to the origin, this is a ray not an infinite line
+    pub fn closest_point(&self, p: Vector3) -> (f32, Vector3) {
+        let t = ((p - self.origin).dot(self.direction) / self.direction.dot(self.direction)).max(0f32);
+
+        (t, self.origin + self.direction * t)
+    }
+
+    /// Returns where this [Ray3D] and another pass closest to each other as `(t_self, t_other, distance)`
+    ///
+    /// Both `t` values are in multiples of each ray's direction and never negative
+    /// Parallel rays are closest along a whole stretch, the one starting at this ray's origin is picked
+    ///
+    /// Reference: Real-Time Collision Detection (Ericson), 5.1.9
+    pub fn closest_approach(&self, other: &Ray3D) -> (f32, f32, f32) {
+        let r = self.origin - other.origin;
+
+        let a = self.direction.dot(self.direction);
+        let b = self.direction.dot(other.direction);
+        let c = other.direction.dot(other.direction);
+        let d = self.direction.dot(r);
+        let e = other.direction.dot(r);
+
+        let denom = a * c - b * b;
+
+        let mut t_self = if denom > EPSILON {
+            ((b * e - c * d) / denom).max(0f32)
+        } else {
+            0f32
+        };
+
+        let mut t_other = (b * t_self + e) / c;
+
+        // The other ray's closest point is behind it, clamp it and find the closest point to its origin instead
+        if t_other < 0f32 {
+            t_other = 0f32;
+            t_self = (-d / a).max(0f32);
+        }
+
+        let distance = ((self.origin + self.direction * t_self) - (other.origin + other.direction * t_other)).magnitude();
+
+        (t_self, t_other, distance)
+    }
+
+    /// Returns the mutually closest points of this [Ray3D] and another as `(on_self, on_other)`, see [Ray3D::closest_approach]
+    pub fn closest_points_between(&self, other: &Ray3D) -> (Vector3, Vector3) {
+        let (t_self, t_other, _) = self.closest_approach(other);
+
+        (self.origin + self.direction * t_self, other.origin + other.direction * t_other)
+    }
+
+    /// Moves this [Ray3D] into another space, the origin is transformed as a point and the direction as a vector
+    ///
+    /// # Note:
+    ///     The direction is NOT renormalized, under scaling `t` values stay comparable between both spaces
+    pub fn transform(&self, m: &Matrix4x4) -> Ray3D {
+        let o = self.origin;
+        let d = self.direction;
+
+        Ray3D {
+            origin: Vector3::from(*m * Vector4::new(o[0], o[1], o[2], 1f32)),
+            direction: Vector3::from(*m * Vector4::new(d[0], d[1], d[2], 0f32)),
+        }
+    }
+
+    /// Möller–Trumbore ray / triangle intersection
+    pub fn intersect_triangle(&self, (p1, p2, p3) : (Vector3, Vector3, Vector3)) -> Option<TriangleHit> {
         let e1 = p2 - p1;
         let e2 = p3 - p1;
 
@@ -35,7 +129,7 @@ impl Ray3D {
         let s = self.origin - p1;
         let u = f * s.dot(h);
 
-        if u < 0.0f32 || u > 1.0f32 {
+        if !(0.0f32 ..= 1.0f32).contains(&u) {
             return None;
         }
 
@@ -49,9 +143,37 @@ impl Ray3D {
         let t = f * e2.dot(q);
 
         if t > EPSILON {
-            return Some((u, v, t));
+            return Some(TriangleHit { u, v, t });
         }
 
-        return None;
+        None
     }
-}
\ No newline at end of file
+
+    /// Intersects every triangle of an indexed mesh, returning the index of the nearest triangle hit and where it was hit
+    ///
+    /// Every 3 `indices` make up a triangle, the returned index is of the triangle (not the first index of it)
+    ///
+    /// # Note:
+    ///     Malformed input is skipped rather than panicking, triangles referencing a vertex out of range are ignored
+    ///     and so are trailing indices that don't make up a whole triangle
+    pub fn intersect_mesh(&self, vertices: &[Vector3], indices: &[u32]) -> Option<(usize, TriangleHit)> {
+        let mut nearest: Option<(usize, TriangleHit)> = None;
+
+        for (triangle, i) in indices.chunks_exact(3).enumerate() {
+            let vertex = |n: usize| vertices.get(i[n] as usize).copied();
+
+            let points = match (vertex(0), vertex(1), vertex(2)) {
+                (Some(a), Some(b), Some(c)) => (a, b, c),
+                _ => continue,
+            };
+
+            if let Some(hit) = self.intersect_triangle(points) {
+                if nearest.is_none_or(|(_, n)| hit.t < n.t) {
+                    nearest = Some((triangle, hit));
+                }
+            }
+        }
+
+        nearest
+    }
+}
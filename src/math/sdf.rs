@@ -0,0 +1,83 @@
+//
+// Signed distance functions
+// Reference: https://iquilezles.org/articles/distfunctions/
+//
+// Each primitive is centered on the origin, transform `p` to move them around
+//
+
+use crate::math::component::Component;
+use crate::math::vector::common::{Vector2, Vector3};
+
+/// Sphere of radius `r`
+#[allow(dead_code)]
+pub fn sphere(p: Vector3, r: f32) -> f32 {
+    p.magnitude() - r
+}
+
+/// Torus lying on the XZ plane, `r1` is the radius of the ring and `r2` the radius of the tube
+pub fn torus(p: Vector3, (r1, r2): (f32, f32)) -> f32 {
+    let y = Vector3::from_array([p[0], 0f32, p[2]]);
+    let q1 = y.magnitude() - r1;
+    let q2 = p[1];
+
+    Vector3::from_array([q1, q2, 0f32]).magnitude() - r2
+}
+
+/// Box with the given half extents along each axis
+#[allow(dead_code)]
+pub fn cuboid(p: Vector3, half_extents: Vector3) -> f32 {
+    let q = p.abs() - half_extents;
+
+    q.max(Vector3::default()).magnitude() + q.max_component().min(0f32)
+}
+
+/// Box with the given half extents whose edges are rounded by `r`, the rounding stays within the half extents
+#[allow(dead_code)]
+pub fn rounded_cuboid(p: Vector3, half_extents: Vector3, r: f32) -> f32 {
+    cuboid(p, half_extents - r) - r
+}
+
+/// Capsule (a line segment from `a` to `b` with thickness) of radius `r`
+#[allow(dead_code)]
+pub fn capsule(p: Vector3, a: Vector3, b: Vector3, r: f32) -> f32 {
+    let pa = p - a;
+    let ba = b - a;
+
+    let h = (pa.dot(ba) / ba.dot(ba)).saturate();
+
+    (pa - ba * h).magnitude() - r
+}
+
+/// Capped cylinder standing along the Y axis, `h` is half of the height
+#[allow(dead_code)]
+pub fn cylinder(p: Vector3, h: f32, r: f32) -> f32 {
+    let d = Vector2::new(Vector2::new(p[0], p[2]).magnitude(), p[1]).abs() - Vector2::new(r, h);
+
+    d.max_component().min(0f32) + d.max(Vector2::default()).magnitude()
+}
+
+/// Infinite plane facing along `normal` (which must be normalized), offset by `-h` along the normal (`h` is the height of the origin above the plane)
+///
+/// The distance is signed, positive above the plane and negative below it
+#[allow(dead_code)]
+pub fn plane(p: Vector3, normal: Vector3, h: f32) -> f32 {
+    p.dot(normal) + h
+}
+
+/// Clips the distance `d` of another primitive against a [plane], removing everything above the plane
+#[allow(dead_code)]
+pub fn half_space(d: f32, p: Vector3, normal: Vector3, h: f32) -> f32 {
+    d.max(plane(p, normal, h))
+}
+
+/// Rounds the edges of a primitive by `r`, growing it outwards by `r`
+#[allow(dead_code)]
+pub fn round(d: f32, r: f32) -> f32 {
+    d - r
+}
+
+/// Stretches a primitive by `h` along each axis, evaluate the primitive with the returned point
+#[allow(dead_code)]
+pub fn elongate(p: Vector3, h: Vector3) -> Vector3 {
+    p - p.max(-h).min(h)
+}
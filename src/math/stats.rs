@@ -0,0 +1,80 @@
+//
+// Streaming statistics over many samples
+//
+
+use crate::math::component::Component;
+use crate::math::vector::Vector;
+
+/// Running mean and variance of a stream of [Vector] samples, tracked per component
+///
+/// Uses Welford's algorithm, which stays accurate over many samples unlike summing squares
+/// https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+#[derive(Copy, Clone)]
+#[allow(dead_code)]
+pub struct Welford<T: Component, const COUNT: usize> {
+    count: usize,
+    mean: Vector<T, COUNT>,
+
+    /// Sum of squared differences from the mean
+    m2: Vector<T, COUNT>,
+}
+
+impl<T: Component, const COUNT: usize> Default for Welford<T, COUNT> {
+    fn default() -> Self {
+        Self { count: 0, mean: Vector::default(), m2: Vector::default() }
+    }
+}
+
+impl<T: Component, const COUNT: usize> Welford<T, COUNT> {
+    #[allow(dead_code)]
+    pub fn add(&mut self, sample: Vector<T, COUNT>) {
+        self.count += 1;
+
+        let delta = sample - self.mean;
+        self.mean += delta / T::from_usize(self.count);
+        self.m2 += delta * (sample - self.mean);
+    }
+
+    /// Number of samples added so far
+    #[allow(dead_code)]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    #[allow(dead_code)]
+    pub fn mean(&self) -> Vector<T, COUNT> {
+        self.mean
+    }
+
+    /// Returns the population variance of the samples, zero until there are any
+    #[allow(dead_code)]
+    pub fn variance(&self) -> Vector<T, COUNT> {
+        if self.count == 0 {
+            return Vector::default();
+        }
+
+        self.m2 / T::from_usize(self.count)
+    }
+
+    /// Returns the unbiased sample variance, zero until there are at least two samples
+    ///
+    /// Prefer this when the samples are a subset of all possible values, eg. estimating pixel noise
+    #[allow(dead_code)]
+    pub fn sample_variance(&self) -> Vector<T, COUNT> {
+        if self.count < 2 {
+            return Vector::default();
+        }
+
+        self.m2 / T::from_usize(self.count - 1)
+    }
+
+    /// Returns the population standard deviation of the samples
+    #[allow(dead_code)]
+    pub fn std_dev(&self) -> Vector<T, COUNT> {
+        let mut v = self.variance();
+
+        v.iter_mut().for_each(|c| *c = c.sqrt_delegate());
+
+        v
+    }
+}
@@ -1 +1,17 @@
-pub mod vector;
\ No newline at end of file
+pub mod component;
+pub mod vector;
+pub mod matrix;
+pub mod sdf;
+pub mod grid;
+pub mod ray;
+pub mod transform;
+pub mod stats;
+pub mod aabb;
+#[cfg(feature = "alloc")]
+pub mod mesh;
+
+#[cfg(feature = "glam")]
+pub mod glam;
+
+#[cfg(feature = "mint")]
+pub mod mint;
@@ -0,0 +1,21 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::math::aabb::Aabb;
+
+#[test]
+fn test_clamp_to_aabb() {
+    let min = Vector3::new(-1f32, 0f32, -2f32);
+    let max = Vector3::new(1f32, 3f32, 2f32);
+
+    // Outside on two axes lands on an edge of the box
+    let p = Vector3::new(5f32, 1f32, -4f32).clamp_to_aabb(min, max);
+    assert_eq!(p, Vector3::new(1f32, 1f32, -2f32));
+
+    let aabb = Aabb::new(min, max);
+    assert_eq!(aabb.clamp_point(Vector3::new(5f32, 1f32, -4f32)), p);
+    assert!(aabb.contains(p));
+
+    // Inside stays put
+    assert_eq!(aabb.clamp_point(Vector3::new(0.5f32, 2f32, 0f32)), Vector3::new(0.5f32, 2f32, 0f32));
+}
@@ -0,0 +1,63 @@
+#![allow(unused)]
+
+use crate::math::component::*;
+
+#[test]
+fn test_saturate() {
+    assert_eq!(2.0f32.saturate(), 1.0f32);
+    assert_eq!((-1.0f32).saturate(), 0.0f32);
+    assert_eq!(0.25f32.saturate(), 0.25f32);
+    assert_eq!((-3.0f64).saturate(), 0.0f64);
+}
+
+#[test]
+fn test_clamp() {
+    assert_eq!(Component::clamp(5f32, -1f32, 2f32), 2f32);
+    assert_eq!(Component::clamp(-5f64, -1f64, 2f64), -1f64);
+}
+
+#[test]
+fn test_epsilon() {
+    assert_eq!(f32::epsilon(), f32::EPSILON);
+    assert_eq!(f64::epsilon(), f64::EPSILON);
+}
+
+#[test]
+fn test_approx_eq() {
+    fn check<T: Component>(a: T, b: T) -> bool {
+        a.approx_eq(b)
+    }
+
+    assert!(check(0.1f32 + 0.2f32, 0.3f32));
+    assert!(check(0.1f64 + 0.2f64, 0.3f64));
+    assert!(!check(0.3f32, 0.3001f32));
+    assert!(!check(0.3f64, 0.3000001f64));
+
+    // Tolerance grows with the magnitude of the values
+    assert!(check(1e6f32, 1e6f32 + 0.0625f32));
+    assert!(!check(1e6f32, 1e6f32 + 1f32));
+}
+
+#[test]
+fn test_angle_conversions() {
+    // Both float types get full precision, not a truncated literal
+    assert!((f64::rad_to_deg() - 57.29577951308232f64).abs() < 1e-13f64);
+    assert!((f64::deg_to_rad() - 0.017453292519943295f64).abs() < 1e-16f64);
+
+    assert!((f32::rad_to_deg() - 57.29578f32).abs() < 1e-5f32);
+    assert!((f32::deg_to_rad() - 0.017453292f32).abs() < 1e-9f32);
+}
+
+#[test]
+fn test_powi() {
+    assert_eq!(3f32.powi_delegate(4), 81f32);
+    assert_eq!((-2f64).powi_delegate(3), -8f64);
+    assert_eq!(2f32.powi_delegate(-2), 0.25f32);
+    assert_eq!(5f64.powi_delegate(0), 1f64);
+
+    // Without std this is square and multiply, it must agree with std's powi either way
+    for (x, n) in [(1.1f32, 10), (-1.3f32, 7), (-0.7f32, -5), (3.7f32, 13)] {
+        assert_eq!(x.powi_delegate(n), x.powi(n), "{}^{}", x, n);
+        assert_eq!((x as f64).powi_delegate(n), (x as f64).powi(n), "{}^{}", x, n);
+    }
+}
@@ -0,0 +1,35 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::math::matrix::common::*;
+
+#[test]
+fn test_glam_vector_round_trip() {
+    let v3 = Vector3::new(1f32, 2f32, 3f32);
+    let g3: glam::Vec3 = v3.into();
+
+    assert_eq!(g3, glam::Vec3::new(1f32, 2f32, 3f32));
+    assert_eq!(Vector3::from(g3), v3);
+
+    let v2 = Vector2::new(1f32, 2f32);
+    assert_eq!(Vector2::from(glam::Vec2::from(v2)), v2);
+
+    let v4 = Vector4::new(1f32, 2f32, 3f32, 4f32);
+    assert_eq!(Vector4::from(glam::Vec4::from(v4)), v4);
+}
+
+#[test]
+fn test_glam_matrix_round_trip() {
+    let m = Matrix4x4::from_array([
+        [1f32, 2f32, 3f32, 4f32],
+        [5f32, 6f32, 7f32, 8f32],
+        [9f32, 10f32, 11f32, 12f32],
+        [13f32, 14f32, 15f32, 16f32],
+    ]);
+
+    let g: glam::Mat4 = m.into();
+
+    // glam is column-major, so our first row is its first element of each column
+    assert_eq!(g.row(0), glam::Vec4::new(1f32, 2f32, 3f32, 4f32));
+    assert_eq!(Matrix4x4::from(g).data, m.data);
+}
@@ -0,0 +1,48 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::math::ray::Ray3D;
+use crate::math::grid;
+
+#[test]
+fn test_traverse_axis_aligned() {
+    let ray = Ray3D::new(Vector3::new(0.5f32, 0.5f32, 0.5f32), Vector3::new(1f32, 0f32, 0f32));
+    let cells: Vec<_> = grid::traverse(&ray, 1f32).take(4).collect();
+
+    assert_eq!(cells, vec![(0, 0, 0), (1, 0, 0), (2, 0, 0), (3, 0, 0)]);
+}
+
+#[test]
+fn test_traverse_negative_direction() {
+    let ray = Ray3D::new(Vector3::new(1f32, 1f32, 1f32), Vector3::new(0f32, 0f32, -1f32));
+    let cells: Vec<_> = grid::traverse(&ray, 0.5f32).take(3).collect();
+
+    assert_eq!(cells, vec![(2, 2, 2), (2, 2, 1), (2, 2, 0)]);
+}
+
+#[test]
+fn test_traverse_diagonal() {
+    let ray = Ray3D::new(Vector3::new(0.5f32, 0.25f32, 0.5f32), Vector3::new(1f32, 1f32, 0f32).normalize());
+    let cells: Vec<_> = grid::traverse(&ray, 1f32).take(3).collect();
+
+    // Starts closer to the X boundary, so crosses it before the Y boundary
+    assert_eq!(cells, vec![(0, 0, 0), (1, 0, 0), (1, 1, 0)]);
+}
+
+#[test]
+#[should_panic]
+fn test_traverse_zero_cell_size() {
+    grid::traverse(&Ray3D::new(Vector3::default(), Vector3::new(1f32, 0f32, 0f32)), 0f32);
+}
+
+#[test]
+#[should_panic]
+fn test_traverse_negative_cell_size() {
+    grid::traverse(&Ray3D::new(Vector3::default(), Vector3::new(1f32, 0f32, 0f32)), -1f32);
+}
+
+#[test]
+#[should_panic]
+fn test_traverse_nan_cell_size() {
+    grid::traverse(&Ray3D::new(Vector3::default(), Vector3::new(1f32, 0f32, 0f32)), f32::NAN);
+}
@@ -0,0 +1,445 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::math::matrix::Matrix;
+use crate::math::matrix::common::*;
+
+#[test]
+fn test_matrix_get() {
+    let mut m = Matrix2x2::from_array([[1f32, 2f32], [3f32, 4f32]]);
+
+    assert_eq!(m.get(0, 1), Some(&2f32));
+    assert_eq!(m.get(1, 0), Some(&3f32));
+    assert_eq!(m.get(2, 0), None);
+    assert_eq!(m.get(0, 2), None);
+
+    *m.get_mut(1, 1).unwrap() = 5f32;
+
+    assert_eq!(m[1][1], 5f32);
+    assert!(m.get_mut(2, 2).is_none());
+}
+
+#[test]
+fn test_matrix_flat_slice() {
+    let m = Matrix2x2::from_array([[1f32, 2f32], [3f32, 4f32]]);
+
+    assert_eq!(m.as_flat_slice(), &[1f32, 2f32, 3f32, 4f32]);
+    assert_eq!(m.as_ptr(), m.as_flat_slice().as_ptr());
+}
+
+#[test]
+fn test_matrix_is_right_handed() {
+    let basis = Matrix3x3::identity();
+    assert!(basis.is_right_handed());
+
+    let mut flipped = basis;
+    flipped[2][2] = -1f32;
+
+    assert!(!flipped.is_right_handed());
+}
+
+#[test]
+fn test_matrix_hadamard() {
+    let a = Matrix2x2::from_array([[1f32, 2f32], [3f32, 4f32]]);
+    let b = Matrix2x2::from_array([[5f32, 6f32], [7f32, 8f32]]);
+
+    assert_eq!(a.hadamard(b).data, [[5f32, 12f32], [21f32, 32f32]]);
+    assert_ne!(a.hadamard(b).data, (a * b).data);
+}
+
+#[test]
+fn test_matrix_from_rows_columns() {
+    let a = Vector3::new(1f32, 2f32, 3f32);
+    let b = Vector3::new(4f32, 5f32, 6f32);
+    let c = Vector3::new(7f32, 8f32, 9f32);
+
+    let rows = Matrix3x3::from_rows([a, b, c]);
+    let columns = Matrix3x3::from_columns([a, b, c]);
+
+    assert_eq!(rows.data, [[1f32, 2f32, 3f32], [4f32, 5f32, 6f32], [7f32, 8f32, 9f32]]);
+    assert_eq!(rows.transpose().data, columns.data);
+}
+
+#[test]
+fn test_matrix_rotation_order() {
+    let euler = Vector3::new(0.3f32, -0.7f32, 1.1f32);
+
+    let x = Matrix4x4::rotate_x(euler[0]);
+    let y = Matrix4x4::rotate_y(euler[1]);
+    let z = Matrix4x4::rotate_z(euler[2]);
+
+    let xyz = Matrix4x4::rotation_order(euler, EulerOrder::XYZ);
+    let zyx = Matrix4x4::rotation_order(euler, EulerOrder::ZYX);
+
+    assert_eq!(xyz.data, (x * y * z).data);
+    assert_eq!(zyx.data, (z * y * x).data);
+    assert_eq!(Matrix4x4::rotation(euler).data, xyz.data);
+
+    assert_ne!(xyz.data, zyx.data);
+}
+
+#[test]
+fn test_matrix_look_to() {
+    let eye = Vector3::new(1f32, 2f32, 3f32);
+    let forward = Vector3::new(1f32, -1f32, -2f32).normalize();
+    let up = Vector3::new(0f32, 1f32, 0f32);
+
+    let m = Matrix4x4::look_to(eye, forward, up);
+    let third = Vector3::new(m[2][0], m[2][1], m[2][2]);
+
+    assert!((third + forward).magnitude() < 0.0001f32);
+
+    // The eye sits at the origin of view space and the forward direction lies down -Z
+    let origin = m * Vector4::new(eye[0], eye[1], eye[2], 1f32);
+    assert!(Vector3::from(origin).magnitude() < 0.0001f32);
+
+    let ahead = eye + forward * 2f32;
+    let view = Vector3::from(m * Vector4::new(ahead[0], ahead[1], ahead[2], 1f32));
+    assert!((view - Vector3::new(0f32, 0f32, -2f32)).magnitude() < 0.0001f32);
+}
+
+#[test]
+fn test_matrix_look_at() {
+    let eye = Vector3::new(0f32, 0f32, 5f32);
+    let up = Vector3::new(0f32, 1f32, 0f32);
+
+    let m = Matrix4x4::look_at(eye, Vector3::default(), up);
+
+    assert_eq!(m.data, Matrix4x4::look_to(eye, Vector3::new(0f32, 0f32, -5f32), up).data);
+}
+
+/// Returns the NDC depth of a point the given distance in front of the camera
+fn projected_depth(m: Matrix4x4, z: f32) -> f32 {
+    let clip = m * Vector4::new(0f32, 0f32, z, 1f32);
+
+    clip[2] / clip[3]
+}
+
+#[test]
+fn test_matrix_translate() {
+    let v = Vector3::new(1f32, -2f32, 3f32);
+
+    // Translation lives in the last column, so it moves points (w = 1)...
+    let moved = Matrix4x4::translate(v) * Vector4::new(0f32, 0f32, 0f32, 1f32);
+    assert_eq!(moved, Vector4::new(1f32, -2f32, 3f32, 1f32));
+
+    // ...but not directions (w = 0)
+    let direction = Vector4::new(0f32, 1f32, 0f32, 0f32);
+    assert_eq!(Matrix4x4::translate(v) * direction, direction);
+}
+
+#[test]
+fn test_matrix_perspective_known_values() {
+    let (fov, aspect, near, far) = (1.2f32, 2f32, 0.1f32, 100f32);
+    let m = Matrix4x4::perspective(fov, aspect, near, far);
+
+    let focal = 1f32 / (fov * 0.5f32).tan();
+
+    assert!((m[1][1] - focal).abs() < 0.0001f32);
+    assert!((m[0][0] - focal / aspect).abs() < 0.0001f32);
+
+    // w takes the distance in front of the camera, which looks down -Z
+    assert_eq!(m[3][2], -1f32);
+    assert_eq!(m[3][3], 0f32);
+    assert!((m[2][3] - -(2f32 * far * near) / (far - near)).abs() < 0.0001f32);
+}
+
+#[test]
+fn test_matrix_perspective_depth_ranges() {
+    let (fov, aspect, near, far) = (1f32, 1.5f32, 0.5f32, 50f32);
+
+    let cases = [
+        (Matrix4x4::perspective_rh_no(fov, aspect, near, far), -1f32, (-1f32, 1f32)),
+        (Matrix4x4::perspective_rh_zo(fov, aspect, near, far), -1f32, (0f32, 1f32)),
+        (Matrix4x4::perspective_lh_no(fov, aspect, near, far), 1f32, (-1f32, 1f32)),
+        (Matrix4x4::perspective_lh_zo(fov, aspect, near, far), 1f32, (0f32, 1f32)),
+    ];
+
+    for (m, forward, (min, max)) in cases {
+        assert!((projected_depth(m, near * forward) - min).abs() < 0.0001f32);
+        assert!((projected_depth(m, far * forward) - max).abs() < 0.0001f32);
+    }
+
+    assert_eq!(Matrix4x4::perspective(fov, aspect, near, far).data, Matrix4x4::perspective_rh_no(fov, aspect, near, far).data);
+}
+
+#[test]
+fn test_matrix_identity_tall() {
+    let m = Matrix::<f32, 2, 4>::identity();
+
+    assert_eq!(m.data, [[1f32, 0f32], [0f32, 1f32], [0f32, 0f32], [0f32, 0f32]]);
+}
+
+#[test]
+fn test_matrix_identity_wide() {
+    let m = Matrix::<f32, 4, 2>::identity();
+
+    assert_eq!(m.data, [[1f32, 0f32, 0f32, 0f32], [0f32, 1f32, 0f32, 0f32]]);
+}
+
+fn assert_matrix_close<const WIDTH: usize, const HEIGHT: usize>(a: Matrix<f32, WIDTH, HEIGHT>, b: Matrix<f32, WIDTH, HEIGHT>, epsilon: f32) {
+    assert!(a.approx_eq(b, epsilon), "\n{}\n!=\n{}", a, b);
+}
+
+/// Deterministic pseudo random numbers in `[-1, 1]`
+fn lcg(state: &mut u32) -> f32 {
+    *state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+
+    (*state >> 8) as f32 / (1u32 << 23) as f32 - 1f32
+}
+
+#[test]
+fn test_matrix_inverse_random() {
+    let mut state = 12345u32;
+
+    for _ in 0 .. 16 {
+        let mut m = Matrix4x4::default();
+
+        for y in 0 .. 4 {
+            for x in 0 .. 4 {
+                m[y][x] = lcg(&mut state) * 4f32;
+            }
+
+            // Keep it well away from singular
+            m[y][y] += 8f32;
+        }
+
+        let i = m.inverse();
+
+        assert_matrix_close(m * i, Matrix4x4::identity(), 0.0001f32);
+        assert_matrix_close(i * m, Matrix4x4::identity(), 0.0001f32);
+    }
+}
+
+#[test]
+fn test_matrix_inverse_rotation() {
+    let r = Matrix4x4::rotation(Vector3::new(0.4f32, -1.3f32, 2.2f32));
+
+    assert_matrix_close(r.inverse(), r.transpose(), 0.0001f32);
+}
+
+#[test]
+fn test_matrix_inverse_small() {
+    let m2 = Matrix2x2::from_array([[4f32, 7f32], [2f32, 6f32]]);
+    assert_matrix_close(m2 * m2.inverse(), Matrix2x2::identity(), 0.0001f32);
+
+    let m3 = Matrix3x3::from_array([[2f32, 0f32, 1f32], [1f32, 3f32, 2f32], [1f32, 1f32, 2f32]]);
+    assert_matrix_close(m3 * m3.inverse(), Matrix3x3::identity(), 0.0001f32);
+}
+
+#[test]
+fn test_matrix_rotate_axis() {
+    let r = Matrix4x4::rotate_axis(Vector3::new(0f32, 1f32, 0f32), 90f32.to_radians());
+    let v = Vector3::from(r * Vector4::new(0f32, 0f32, 1f32, 1f32));
+
+    assert!((v - Vector3::new(1f32, 0f32, 0f32)).magnitude() < 0.0001f32, "{}", v);
+
+    // Unnormalized axes give the same rotation as the matching rotate_x/y/z
+    assert_matrix_close(Matrix4x4::rotate_axis(Vector3::new(0f32, 0f32, 3f32), 0.7f32), Matrix4x4::rotate_z(0.7f32), 0.0001f32);
+}
+
+#[test]
+fn test_matrix_from_quaternion() {
+    assert_matrix_close(Matrix4x4::from_quaternion(Quaternion::identity()), Matrix4x4::identity(), 0f32);
+
+    let q = Quaternion::from_axis_angle(Vector3::new(0f32, 0f32, 1f32), std::f32::consts::FRAC_PI_2);
+    assert_matrix_close(Matrix4x4::from_quaternion(q), Matrix4x4::rotate_z(std::f32::consts::FRAC_PI_2), 0.0001f32);
+}
+
+#[test]
+fn test_matrix_iterators() {
+    let m = Matrix2x2::from_array([[1f32, 2f32], [3f32, 4f32]]);
+
+    assert_eq!(m.iter_elements().collect::<Vec<_>>(), [1f32, 2f32, 3f32, 4f32]);
+    assert_eq!(m.iter_rows().collect::<Vec<_>>(), [Vector2::new(1f32, 2f32), Vector2::new(3f32, 4f32)]);
+}
+
+#[test]
+fn test_matrix_determinant_generic() {
+    let m3 = Matrix3x3::from_array([[2f32, 0f32, 1f32], [1f32, 3f32, 2f32], [1f32, 1f32, 2f32]]);
+    assert!((m3.determinant_generic() - m3.determinant()).abs() < 0.0001f32);
+
+    // Needs a row swap to find a pivot
+    let m2 = Matrix2x2::from_array([[0f32, 2f32], [3f32, 1f32]]);
+    assert_eq!(m2.determinant_generic(), -6f32);
+}
+
+#[test]
+fn test_matrix_to_gl_array() {
+    let m = Matrix4x4::translate(Vector3::new(1f32, 2f32, 3f32)) * Matrix4x4::rotation(Vector3::new(0.3f32, 0.2f32, 0.1f32));
+
+    assert_eq!(m.to_gl_array().as_slice(), m.transpose().as_flat_slice());
+
+    // Translation ends up in the last four elements like GL expects
+    assert_eq!(&m.to_gl_array()[12 .. 15], &[1f32, 2f32, 3f32]);
+}
+
+#[test]
+fn test_matrix_approx_eq() {
+    let m = Matrix4x4::translate(Vector3::new(1f32, -2f32, 3f32)) * Matrix4x4::rotation(Vector3::new(0.5f32, 0.1f32, -0.7f32));
+
+    assert!((m.inverse() * m).approx_eq(Matrix4x4::identity(), 0.0001f32));
+    assert!(!m.approx_eq(Matrix4x4::identity(), 0.0001f32));
+}
+
+#[test]
+fn test_matrix_set_row_col() {
+    let mut m = Matrix3x3::identity();
+
+    m.set_row(1, Vector3::new(4f32, 5f32, 6f32));
+    assert_eq!(m.row(1), Vector3::new(4f32, 5f32, 6f32));
+    assert_eq!(m.row(0), Vector3::new(1f32, 0f32, 0f32));
+
+    m.set_col(2, Vector3::new(7f32, 8f32, 9f32));
+    assert_eq!(m.col(2), Vector3::new(7f32, 8f32, 9f32));
+    assert_eq!(m.row(1), Vector3::new(4f32, 5f32, 8f32));
+}
+
+#[test]
+fn test_matrix_cached_inverse() {
+    let m = Matrix4x4::translate(Vector3::new(1f32, 2f32, 3f32)) * Matrix4x4::rotation(Vector3::new(0.2f32, 0.4f32, 0.6f32));
+    let mut cached = CachedInverse::new(m);
+
+    assert!(!cached.is_cached());
+
+    let first = cached.inverse();
+    assert!(cached.is_cached());
+
+    let second = cached.inverse();
+    assert_eq!(first.as_flat_slice(), second.as_flat_slice());
+    assert_eq!(first.as_flat_slice(), m.inverse().as_flat_slice());
+
+    // Any mutation throws the inverse away
+    cached.matrix_mut()[0][3] = 5f32;
+    assert!(!cached.is_cached());
+    assert!((cached.inverse() * cached.matrix()).approx_eq(Matrix4x4::identity(), 0.0001f32));
+
+    cached.set(Matrix4x4::identity());
+    assert!(!cached.is_cached());
+}
+
+#[test]
+fn test_matrix_condition_number_estimate() {
+    let rotation = Matrix4x4::rotation(Vector3::new(0.3f32, -1.1f32, 2.4f32));
+    assert!((rotation.condition_number_estimate() - 1f32).abs() < 0.001f32);
+
+    let scale = Matrix3x3::from_array([[4f32, 0f32, 0f32], [0f32, 2f32, 0f32], [0f32, 0f32, 0.5f32]]);
+    assert!((scale.condition_number_estimate() - 8f32).abs() < 0.001f32);
+
+    // Second row is almost a multiple of the first
+    let nearly_singular = Matrix2x2::from_array([[1f32, 2f32], [1f32, 2.0001f32]]);
+    assert!(nearly_singular.condition_number_estimate() > 10000f32);
+
+    let singular = Matrix2x2::from_array([[1f32, 2f32], [2f32, 4f32]]);
+    assert!(singular.condition_number_estimate().is_infinite());
+}
+
+#[test]
+fn test_matrix_condition_number_estimate_equal_row_sums() {
+    // (1, 1) is an eigenvector of both AᵀA and A⁻ᵀA⁻¹ here, starting the iteration on it would report 1
+    let m = Matrix2x2::from_array([[2f32, 1f32], [1f32, 2f32]]);
+    assert!((m.condition_number_estimate() - 3f32).abs() < 0.001f32);
+
+    let m = Matrix3x3::from_array([[4f32, 1f32, 1f32], [1f32, 4f32, 1f32], [1f32, 1f32, 4f32]]);
+    assert!((m.condition_number_estimate() - 2f32).abs() < 0.001f32);
+}
+
+#[test]
+fn test_matrix_perspective_fov() {
+    let (fov_y, aspect) = (0.9f32, 16f32 / 9f32);
+    let fov_x = 2f32 * ((fov_y * 0.5f32).tan() * aspect).atan();
+
+    assert_matrix_close(Matrix4x4::perspective_fov(fov_x, fov_y, 0.1f32, 50f32), Matrix4x4::perspective(fov_y, aspect, 0.1f32, 50f32), 0.0001f32);
+}
+
+#[test]
+fn test_matrix_perspective_reverse_z() {
+    let (near, far) = (0.5f32, 50f32);
+    let m = Matrix4x4::perspective_reverse_z(1f32, 1.5f32, near, far);
+
+    assert!((projected_depth(m, -near) - 1f32).abs() < 0.0001f32);
+    assert!(projected_depth(m, -far).abs() < 0.0001f32);
+
+    // Further away is smaller
+    assert!(projected_depth(m, -2f32) > projected_depth(m, -20f32));
+}
+
+#[test]
+fn test_matrix_from_str() {
+    let m = Matrix4x4::translate(Vector3::new(1.5f32, -2f32, 3f32)) * Matrix4x4::rotation(Vector3::new(0.3f32, 0.2f32, 0.1f32));
+
+    let parsed: Matrix4x4 = m.to_string().parse().unwrap();
+    assert_eq!(parsed.as_flat_slice(), m.as_flat_slice());
+
+    let m2: Matrix2x2 = " [1, 2.5]\n[-3,4] ".parse().unwrap();
+    assert_eq!(m2.as_flat_slice(), &[1f32, 2.5f32, -3f32, 4f32]);
+}
+
+#[test]
+fn test_matrix_from_str_errors() {
+    use crate::math::matrix::ParseMatrixError;
+
+    assert_eq!("[1, 2]".parse::<Matrix2x2>().err(), Some(ParseMatrixError::RowCount));
+    assert_eq!("[1, 2]\n[3, 4]\n[5, 6]".parse::<Matrix2x2>().err(), Some(ParseMatrixError::RowCount));
+    assert_eq!("[1, 2]\n3, 4".parse::<Matrix2x2>().err(), Some(ParseMatrixError::Brackets { row: 1 }));
+    assert_eq!("[1, 2, 3]\n[3, 4]".parse::<Matrix2x2>().err(), Some(ParseMatrixError::ColumnCount { row: 0 }));
+    assert_eq!("[1, x]\n[3, 4]".parse::<Matrix2x2>().err(), Some(ParseMatrixError::Component { row: 0, col: 1 }));
+}
+
+#[test]
+fn test_matrix_unproject() {
+    let eye = Vector3::new(1f32, 2f32, 3f32);
+    let forward = Vector3::new(0f32, 0f32, -1f32);
+
+    let view = Matrix4x4::look_to(eye, forward, Vector3::new(0f32, 1f32, 0f32));
+    let vp_i = (Matrix4x4::perspective(1f32, 1.5f32, 0.1f32, 100f32) * view).inverse();
+
+    // The center of the screen is straight down the view ray
+    for z in [-1f32, 0f32, 0.5f32] {
+        let p = vp_i.unproject(Vector3::new(0f32, 0f32, z));
+        let along = p - eye;
+
+        assert!(along.dot(forward) > 0f32);
+        assert!((along.normalize() - forward).magnitude() < 0.0001f32);
+    }
+
+    // The near plane is where it was asked to be
+    let near = vp_i.unproject(Vector3::new(0f32, 0f32, -1f32));
+    assert!(((near - eye).magnitude() - 0.1f32).abs() < 0.0001f32);
+}
+
+#[test]
+fn test_matrix_billboard() {
+    let position = Vector3::new(1f32, 0f32, -2f32);
+    let camera = Vector3::new(4f32, 2f32, 4f32);
+    let up = Vector3::new(0f32, 1f32, 0f32);
+
+    let m = Matrix4x4::billboard(position, camera, up);
+
+    // Local +Z points from the sprite towards the camera
+    let forward = Vector3::from(m * Vector4::new(0f32, 0f32, 1f32, 0f32));
+    assert!((forward - (camera - position).normalize()).magnitude() < 0.0001f32);
+
+    // The local origin is the sprite's position
+    let origin = Vector3::from(m * Vector4::new(0f32, 0f32, 0f32, 1f32));
+    assert!((origin - position).magnitude() < 0.0001f32);
+
+    // Up stays up as far as the camera can tell
+    let local_up = Vector3::from(m * Vector4::new(0f32, 1f32, 0f32, 0f32));
+    assert!(local_up[1] > 0f32);
+    assert!(local_up.dot(forward).abs() < 0.0001f32);
+
+    assert_matrix_close(m.inverse(), Matrix4x4::look_to(position, position - camera, up), 0.0001f32);
+}
+
+#[test]
+fn test_matrix_from_fn_map() {
+    let m = Matrix3x3::from_fn(|y, x| (y * 3 + x) as f32);
+
+    assert_eq!(m.data, [[0f32, 1f32, 2f32], [3f32, 4f32, 5f32], [6f32, 7f32, 8f32]]);
+    assert_eq!(m.map(|e| e * 2f32).data, [[0f32, 2f32, 4f32], [6f32, 8f32, 10f32], [12f32, 14f32, 16f32]]);
+
+    // Non square matrices are called with (row, col) too
+    let wide = Matrix::<f32, 3, 2>::from_fn(|y, x| (y * 10 + x) as f32);
+    assert_eq!(wide.data, [[0f32, 1f32, 2f32], [10f32, 11f32, 12f32]]);
+}
@@ -0,0 +1,44 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::math::aabb::Aabb;
+use crate::math::mesh::*;
+
+/// Square pyramid with its base on the XZ plane
+fn pyramid() -> Mesh {
+    let vertices = vec![
+        Vector3::new(-1f32, 0f32, -1f32),
+        Vector3::new(1f32, 0f32, -1f32),
+        Vector3::new(1f32, 0f32, 1f32),
+        Vector3::new(-1f32, 0f32, 1f32),
+        Vector3::new(0.25f32, 2f32, 0f32),
+    ];
+
+    Mesh::new(vertices, &[0, 1, 2, 0, 2, 3, 0, 4, 1, 1, 4, 2, 2, 4, 3, 3, 4, 0])
+}
+
+#[test]
+fn test_mesh_bounds() {
+    let mesh = pyramid();
+    let bounds = mesh.bounds();
+
+    assert_eq!(bounds, Aabb::new(Vector3::new(-1f32, 0f32, -1f32), Vector3::new(1f32, 2f32, 1f32)));
+    assert!(mesh.vertices.iter().all(|v| bounds.contains(*v)));
+}
+
+#[test]
+fn test_mesh_triangles() {
+    let mesh = pyramid();
+
+    assert_eq!(mesh.triangles().len(), 6);
+    assert_eq!(mesh.triangles()[2], Triangle { indices: [0, 4, 1] });
+    assert_eq!(mesh.triangle_vertices(mesh.triangles()[2]).1, Vector3::new(0.25f32, 2f32, 0f32));
+}
+
+#[test]
+fn test_empty_mesh_bounds() {
+    let mesh = Mesh::new(Vec::new(), &[]);
+
+    assert_eq!(mesh.bounds(), Aabb::empty());
+    assert!(!mesh.bounds().contains(Vector3::default()));
+}
@@ -0,0 +1,35 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::math::matrix::common::*;
+
+#[test]
+fn test_mint_vector_round_trip() {
+    let v3 = Vector3::new(1f32, 2f32, 3f32);
+    let m3: mint::Vector3<f32> = v3.into();
+
+    assert_eq!(m3, mint::Vector3 { x: 1f32, y: 2f32, z: 3f32 });
+    assert_eq!(Vector3::from(m3), v3);
+
+    let v2 = Vector2::new(1f32, 2f32);
+    assert_eq!(Vector2::from(mint::Vector2::from(v2)), v2);
+
+    let v4 = Vector4::new(1f32, 2f32, 3f32, 4f32);
+    assert_eq!(Vector4::from(mint::Vector4::from(v4)), v4);
+}
+
+#[test]
+fn test_mint_matrix_round_trip() {
+    let m = Matrix4x4::from_array([
+        [1f32, 2f32, 3f32, 4f32],
+        [5f32, 6f32, 7f32, 8f32],
+        [9f32, 10f32, 11f32, 12f32],
+        [13f32, 14f32, 15f32, 16f32],
+    ]);
+
+    let c: mint::ColumnMatrix4<f32> = m.into();
+
+    // The first column holds the first element of each row
+    assert_eq!(c.x, mint::Vector4 { x: 1f32, y: 5f32, z: 9f32, w: 13f32 });
+    assert_eq!(Matrix4x4::from(c).data, m.data);
+}
@@ -0,0 +1,171 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::math::ray::*;
+use crate::math::matrix::common::*;
+
+/// Unit quad on the XY plane at the given depth, made of two triangles
+fn quad(z: f32) -> [Vector3; 4] {
+    [
+        Vector3::new(-1f32, -1f32, z),
+        Vector3::new(1f32, -1f32, z),
+        Vector3::new(1f32, 1f32, z),
+        Vector3::new(-1f32, 1f32, z),
+    ]
+}
+
+#[test]
+fn test_intersect_triangle() {
+    let ray = Ray3D::new(Vector3::new(0.5f32, -0.5f32, 5f32), Vector3::new(0f32, 0f32, -1f32));
+    let q = quad(0f32);
+
+    let hit = ray.intersect_triangle((q[0], q[1], q[2])).unwrap();
+
+    assert!((hit.t - 5f32).abs() < 0.0001f32);
+    assert!(ray.intersect_triangle((q[0], q[2], q[3])).is_none());
+}
+
+#[test]
+fn test_intersect_mesh_quad() {
+    let indices = [0, 1, 2, 0, 2, 3];
+
+    let ray = Ray3D::new(Vector3::new(-0.5f32, 0.5f32, 5f32), Vector3::new(0f32, 0f32, -1f32));
+    let (triangle, hit) = ray.intersect_mesh(&quad(0f32), &indices).unwrap();
+
+    assert_eq!(triangle, 1);
+    assert!((hit.t - 5f32).abs() < 0.0001f32);
+
+    let miss = Ray3D::new(Vector3::new(2f32, 0f32, 5f32), Vector3::new(0f32, 0f32, -1f32));
+    assert!(miss.intersect_mesh(&quad(0f32), &indices).is_none());
+}
+
+#[test]
+fn test_intersect_mesh_nearest() {
+    // Two quads, the far one listed first
+    let mut vertices = quad(-2f32).to_vec();
+    vertices.extend_from_slice(&quad(1f32));
+
+    let indices = [0, 1, 2, 0, 2, 3, 4, 5, 6, 4, 6, 7];
+
+    let ray = Ray3D::new(Vector3::new(0.5f32, -0.5f32, 5f32), Vector3::new(0f32, 0f32, -1f32));
+    let (triangle, hit) = ray.intersect_mesh(&vertices, &indices).unwrap();
+
+    assert_eq!(triangle, 2);
+    assert!((hit.t - 4f32).abs() < 0.0001f32);
+}
+
+#[test]
+fn test_intersect_mesh_malformed() {
+    let ray = Ray3D::new(Vector3::new(-0.5f32, 0.5f32, 5f32), Vector3::new(0f32, 0f32, -1f32));
+
+    // The second triangle would be hit, but it points past the end of the vertices
+    assert!(ray.intersect_mesh(&quad(0f32), &[0, 1, 2, 0, 2, 9]).is_none());
+
+    // A trailing partial triangle is ignored, the whole one in front of it still counts
+    let (triangle, _) = ray.intersect_mesh(&quad(0f32), &[0, 2, 3, 0, 1]).unwrap();
+    assert_eq!(triangle, 0);
+
+    assert!(ray.intersect_mesh(&quad(0f32), &[0, 2]).is_none());
+}
+
+#[test]
+fn test_ray_transform_translation() {
+    let ray = Ray3D::new(Vector3::new(1f32, 2f32, 3f32), Vector3::new(0f32, 0.6f32, -0.8f32));
+    let moved = ray.transform(&Matrix4x4::translate(Vector3::new(-1f32, 0f32, 2f32)));
+
+    assert_eq!(moved.origin, Vector3::new(0f32, 2f32, 5f32));
+    assert_eq!(moved.direction, ray.direction);
+}
+
+#[test]
+fn test_ray_closest_point() {
+    let ray = Ray3D::new(Vector3::default(), Vector3::new(0f32, 0f32, 2f32));
+
+    assert_eq!(ray.closest_point(Vector3::new(1f32, 0f32, 5f32)), (2.5f32, Vector3::new(0f32, 0f32, 5f32)));
+
+    // Behind the ray clamps to the origin
+    assert_eq!(ray.closest_point(Vector3::new(1f32, 1f32, -3f32)), (0f32, Vector3::default()));
+}
+
+#[test]
+fn test_ray_closest_point_off_axis() {
+    let ray = Ray3D::new(Vector3::new(0f32, 1f32, 0f32), Vector3::new(1f32, 0f32, 0f32));
+
+    let p = Vector3::new(3f32, 4f32, -2f32);
+    let (t, closest) = ray.closest_point(p);
+
+    assert_eq!(t, 3f32);
+    assert_eq!(closest, Vector3::new(3f32, 1f32, 0f32));
+
+    // The offset to the point is perpendicular to the ray
+    assert_eq!((p - closest).dot(ray.direction), 0f32);
+}
+
+#[test]
+fn test_ray_closest_points_between() {
+    let a = Ray3D::new(Vector3::default(), Vector3::new(1f32, 0f32, 0f32));
+    let b = Ray3D::new(Vector3::new(2f32, 1f32, -3f32), Vector3::new(0f32, 0f32, 1f32));
+
+    let (on_a, on_b) = a.closest_points_between(&b);
+
+    assert!((on_a - Vector3::new(2f32, 0f32, 0f32)).magnitude() < 0.0001f32);
+    assert!((on_b - Vector3::new(2f32, 1f32, 0f32)).magnitude() < 0.0001f32);
+
+    // Parallel rays pick the stretch starting at the first ray's origin
+    let c = Ray3D::new(Vector3::new(-4f32, 2f32, 0f32), Vector3::new(1f32, 0f32, 0f32));
+    let (on_a, on_c) = a.closest_points_between(&c);
+
+    assert!((on_a - Vector3::default()).magnitude() < 0.0001f32);
+    assert!((on_c - Vector3::new(0f32, 2f32, 0f32)).magnitude() < 0.0001f32);
+}
+
+#[test]
+fn test_ray_closest_approach_skew() {
+    let a = Ray3D::new(Vector3::default(), Vector3::new(1f32, 0f32, 0f32));
+    let b = Ray3D::new(Vector3::new(2f32, 1f32, -3f32), Vector3::new(0f32, 0f32, 1f32));
+
+    let (t_a, t_b, distance) = a.closest_approach(&b);
+
+    assert!((t_a - 2f32).abs() < 0.0001f32);
+    assert!((t_b - 3f32).abs() < 0.0001f32);
+    assert!((distance - 1f32).abs() < 0.0001f32);
+}
+
+#[test]
+fn test_ray_closest_approach_parallel() {
+    let a = Ray3D::new(Vector3::default(), Vector3::new(1f32, 0f32, 0f32));
+    let b = Ray3D::new(Vector3::new(-4f32, 2f32, 0f32), Vector3::new(1f32, 0f32, 0f32));
+
+    let (t_a, t_b, distance) = a.closest_approach(&b);
+
+    assert!((distance - 2f32).abs() < 0.0001f32);
+    assert!((t_a - 0f32).abs() < 0.0001f32);
+    assert!((t_b - 4f32).abs() < 0.0001f32);
+}
+
+#[test]
+fn test_ray_closest_approach_behind() {
+    // The lines cross behind the second ray, so its origin is the closest it gets
+    let a = Ray3D::new(Vector3::default(), Vector3::new(1f32, 0f32, 0f32));
+    let b = Ray3D::new(Vector3::new(3f32, 0f32, 2f32), Vector3::new(0f32, 0f32, 1f32));
+
+    let (t_a, t_b, distance) = a.closest_approach(&b);
+
+    assert_eq!(t_b, 0f32);
+    assert!((t_a - 3f32).abs() < 0.0001f32);
+    assert!((distance - 2f32).abs() < 0.0001f32);
+}
+
+#[test]
+fn test_ray_new_normalized() {
+    let ray = Ray3D::new_normalized(Vector3::new(1f32, 2f32, 3f32), Vector3::new(3f32, 0f32, -4f32));
+
+    assert!((ray.direction.magnitude() - 1f32).abs() < 0.0001f32);
+    assert_eq!(ray.direction, Vector3::new(0.6f32, 0f32, -0.8f32));
+
+    // t is now a distance
+    let q = [Vector3::new(-1f32, -1f32, 0f32), Vector3::new(1f32, -1f32, 0f32), Vector3::new(0f32, 1f32, 0f32)];
+    let hit = Ray3D::new_normalized(Vector3::new(0f32, 0f32, 5f32), Vector3::new(0f32, 0f32, -10f32)).intersect_triangle((q[0], q[1], q[2])).unwrap();
+
+    assert!((hit.t - 5f32).abs() < 0.0001f32);
+}
@@ -0,0 +1,59 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::math::sdf;
+
+#[test]
+fn test_plane_height() {
+    let up = Vector3::new(0f32, 1f32, 0f32);
+
+    for p in [Vector3::new(0f32, 2f32, 0f32), Vector3::new(5f32, -3f32, 1f32), Vector3::new(-1f32, 0f32, 7f32)] {
+        assert_eq!(sdf::plane(p, up, 0f32), p[1]);
+    }
+
+    // Lowering the plane raises everything above it
+    assert_eq!(sdf::plane(Vector3::new(0f32, 2f32, 0f32), up, 1f32), 3f32);
+
+    // The surface sits `h` against the normal
+    assert_eq!(sdf::plane(Vector3::new(4f32, -1f32, 2f32), up, 1f32), 0f32);
+}
+
+#[test]
+fn test_half_space() {
+    let up = Vector3::new(0f32, 1f32, 0f32);
+
+    // Bottom of the sphere is untouched, top is cut away by the plane
+    let bottom = Vector3::new(0f32, -1f32, 0f32);
+    let top = Vector3::new(0f32, 1f32, 0f32);
+
+    assert_eq!(sdf::half_space(sdf::sphere(bottom, 1f32), bottom, up, 0f32), 0f32);
+    assert_eq!(sdf::half_space(sdf::sphere(top, 1f32), top, up, 0f32), 1f32);
+}
+
+#[test]
+fn test_round_cuboid() {
+    let b = Vector3::new(1f32, 0.5f32, 0.25f32);
+    let r = 0.1f32;
+
+    for p in [Vector3::new(2f32, 0f32, 0f32), Vector3::new(0f32, -3f32, 0f32), Vector3::new(1.5f32, 1f32, 1f32)] {
+        let d = sdf::cuboid(p, b);
+
+        assert!((sdf::round(d, r) - (d - r)).abs() < 0.0001f32);
+    }
+
+    // The rounded surface sits `r` outside the original face
+    let face = Vector3::new(1f32 + r, 0f32, 0f32);
+    assert!(sdf::round(sdf::cuboid(face, b), r).abs() < 0.0001f32);
+}
+
+#[test]
+fn test_elongate_sphere() {
+    let h = Vector3::new(1f32, 0f32, 0f32);
+
+    // Stretched along X the sphere's surface moves out by h, but not along the other axes
+    let side = Vector3::new(1.5f32, 0f32, 0f32);
+    let top = Vector3::new(0.5f32, 0.5f32, 0f32);
+
+    assert_eq!(sdf::sphere(sdf::elongate(side, h), 0.5f32), 0f32);
+    assert_eq!(sdf::sphere(sdf::elongate(top, h), 0.5f32), 0f32);
+}
@@ -0,0 +1,35 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::math::stats::Welford;
+
+#[test]
+fn test_vector_variance() {
+    let a = Vector3::new(2f32, 4f32, 6f32);
+
+    assert!((a.variance() - 8f32 / 3f32).abs() < 0.0001f32);
+    assert!((a.std_dev() - (8f32 / 3f32).sqrt()).abs() < 0.0001f32);
+}
+
+#[test]
+fn test_welford() {
+    let samples = [
+        Vector3::new(2f32, 1f32, 0f32),
+        Vector3::new(4f32, 1f32, 10f32),
+        Vector3::new(6f32, 1f32, -10f32),
+    ];
+
+    let mut welford = Welford::default();
+    samples.iter().for_each(|s| welford.add(*s));
+
+    assert_eq!(welford.count(), 3);
+    assert!((welford.mean() - Vector3::new(4f32, 1f32, 0f32)).magnitude() < 0.0001f32);
+
+    // The first component matches the variance of (2, 4, 6)
+    let variance = welford.variance();
+    assert!((variance[0] - Vector3::new(2f32, 4f32, 6f32).variance()).abs() < 0.0001f32);
+    assert_eq!(variance[1], 0f32);
+    assert!((variance[2] - 200f32 / 3f32).abs() < 0.001f32);
+
+    assert!((welford.sample_variance()[0] - 4f32).abs() < 0.0001f32);
+}
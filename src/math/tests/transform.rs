@@ -0,0 +1,30 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::math::matrix::common::*;
+use crate::math::transform::*;
+
+#[test]
+fn test_transform2d_point() {
+    let transform = Transform2D {
+        position: Vector2::new(2f32, 0f32),
+        rotation: 90f32.to_radians(),
+        scale: Vector2::new(3f32, 1f32),
+    };
+
+    // Scaled to (3, 0), rotated to (0, 3), then moved to (2, 3)
+    let p = transform.transform_point(Vector2::new(1f32, 0f32));
+    assert!((p - Vector2::new(2f32, 3f32)).magnitude() < 0.0001f32, "{}", p);
+
+    // Composing the matrices by hand gives the same result
+    let translate = Matrix3x3::from_array([[1f32, 0f32, 2f32], [0f32, 1f32, 0f32], [0f32, 0f32, 1f32]]);
+    let rotate = Transform2D { rotation: 90f32.to_radians(), ..Transform2D::default() }.to_matrix();
+    let scale = Transform2D { scale: Vector2::new(3f32, 1f32), ..Transform2D::default() }.to_matrix();
+
+    assert!((translate * rotate * scale).approx_eq(transform.to_matrix(), 0.0001f32));
+}
+
+#[test]
+fn test_transform2d_default_is_identity() {
+    assert!(Transform2D::default().to_matrix().approx_eq(Matrix3x3::identity(), 0f32));
+}
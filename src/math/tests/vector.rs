@@ -1,19 +1,108 @@
 #![allow(unused)]
 
+/// Returns true if `a` and `b` are within `epsilon` of each other
+pub fn approx_eq(a: f32, b: f32, epsilon: f32) -> bool {
+    (a - b).abs() <= epsilon
+}
+
 mod vector2 {
     use crate::math::vector::common::*;
+    use super::approx_eq;
 
     //
     // V2 only operations
     //
+    #[test]
     fn test_v2_normalization() {
         let mut a = Vector2::from_single(1.0f32);
 
         // Test 4 times for sanity reasons!
         for _ in 0 .. 4 {
-            assert_eq!(a.normalize().magnitude(), 1f32);
+            assert!(approx_eq(a.normalize().magnitude(), 1f32, 0.00001f32), "{}", a);
             a *= 2f32;
         }
+
+        // Zero has no direction, normalizing in place shouldn't fill it with NaN
+        let mut zero = Vector2::default();
+        zero.normalize_mut();
+
+        assert_eq!(zero, Vector2::default());
+    }
+
+    #[test]
+    fn test_v2_wedge() {
+        let a = Vector2::new(2f32, 1f32);
+        let b = Vector2::new(-1f32, 3f32);
+
+        // Perp dot, the dot product with `a` rotated 90 degrees counter clockwise
+        assert_eq!(a.wedge(b), Vector2::new(-a[1], a[0]).dot(b));
+        assert_eq!(a.wedge(b), 7f32);
+        assert_eq!(b.wedge(a), -7f32);
+    }
+
+    #[test]
+    fn test_v2_rotate() {
+        let a = Vector2::new(1f32, 0f32).rotate(90f32.to_radians());
+
+        assert!((a - Vector2::new(0f32, 1f32)).magnitude() < 0.0001f32, "{}", a);
+    }
+
+    #[test]
+    fn test_v2_distance_l1_linf() {
+        let a = Vector2::new(1f32, 5f32);
+        let b = Vector2::new(4f32, 1f32);
+
+        assert_eq!(a.distance_l1(b), 7f32);
+        assert_eq!(a.distance_linf(b), 4f32);
+        assert_eq!(b.distance_l1(a), a.distance_l1(b));
+    }
+
+    #[test]
+    fn test_v2_powi_sqrt_cbrt() {
+        let c = Vector2::new(8f32, 27f32).cbrt();
+        assert!((c - Vector2::new(2f32, 3f32)).magnitude() < 0.0001f32, "{}", c);
+
+        assert_eq!(Vector2::new(-8f32, 0f32).cbrt(), Vector2::new(-2f32, 0f32));
+        assert_eq!(Vector2::new(4f32, 9f32).sqrt(), Vector2::new(2f32, 3f32));
+        assert_eq!(Vector2::new(-2f32, 3f32).powi(3), Vector2::new(-8f32, 27f32));
+        assert_eq!(Vector2::new(2f32, 4f32).powi(-1), Vector2::new(0.5f32, 0.25f32));
+    }
+
+    #[test]
+    fn test_v2_l1_norm() {
+        assert_eq!(Vector2::new(3f32, -4f32).l1_norm(), 7f32)
+    }
+
+    #[test]
+    fn test_v2_linf_norm() {
+        assert_eq!(Vector2::new(3f32, -4f32).linf_norm(), 4f32)
+    }
+
+    #[test]
+    fn test_v2_reflect() {
+        let a = Vector2::new(1f32, -1f32);
+
+        assert_eq!(a.reflect(Vector2::new(0f32, 1f32)), Vector2::new(1f32, 1f32))
+    }
+
+    #[test]
+    fn test_v2_refract() {
+        let normal = Vector2::new(0f32, 1f32);
+        let incident = Vector2::new(1f32, -1f32).normalize();
+
+        // Matching indices pass straight through
+        assert!((incident.refract(normal, 1f32) - incident).magnitude() < 0.0001f32);
+
+        // Snell's law, sin(theta_t) = eta * sin(theta_i)
+        let eta = 1f32 / 1.5f32;
+        let refracted = incident.refract(normal, eta);
+
+        assert!((refracted.magnitude() - 1f32).abs() < 0.0001f32);
+        assert!((refracted[0] - eta * incident[0]).abs() < 0.0001f32);
+        assert!(refracted[1] < 0f32);
+
+        // Total internal reflection going from glass into air at a grazing angle
+        assert_eq!(Vector2::new(1f32, -0.1f32).normalize().refract(normal, 1.5f32), Vector2::default());
     }
 
     //
@@ -23,18 +112,259 @@ mod vector2 {
 
 mod vector3 {
     use crate::math::vector::common::*;
+    use super::approx_eq;
 
     //
     // V3 only operations
     //
+    #[test]
+    fn test_v3_min_max() {
+        let a = Vector3::new(1f32, -5f32, 3f32);
+        let b = Vector3::new(2f32, -6f32, 3f32);
+
+        assert_eq!(a.min(b), Vector3::new(1f32, -6f32, 3f32));
+        assert_eq!(a.max(b), Vector3::new(2f32, -5f32, 3f32));
+
+        // Component wise, each axis clamps on its own
+        let lo = Vector3::new(0f32, -1f32, 4f32);
+        let hi = Vector3::new(0.5f32, 1f32, 5f32);
+        assert_eq!(a.clamp(lo, hi), Vector3::new(0.5f32, -1f32, 4f32));
+    }
+
+    #[test]
+    fn test_v3_max_component() {
+        assert_eq!(Vector3::new(1f32, -5f32, 3f32).max_component(), 3f32);
+        assert_eq!(Vector3::new(-4f32, -2f32, -3f32).max_component(), -2f32);
+    }
+
+    #[test]
+    fn test_v3_lerp() {
+        let a = Vector3::new(0f32, 2f32, -4f32);
+        let b = Vector3::new(4f32, 2f32, 0f32);
+
+        assert_eq!(a.lerp(b, 0f32), a);
+        assert_eq!(a.lerp(b, 1f32), b);
+        assert_eq!(a.lerp(b, 0.25f32), Vector3::new(1f32, 2f32, -3f32));
+    }
+
+    #[test]
+    fn test_v3_nlerp() {
+        let a = Vector3::new(1f32, 0f32, 0f32);
+        let b = Vector3::new(0f32, 0.6f32, 0.8f32);
+
+        for i in 0 ..= 10 {
+            let t = i as f32 / 10f32;
+            assert!(approx_eq(a.nlerp(b, t).magnitude(), 1f32, 0.00001f32), "{}", t);
+        }
+
+        for t in [0f32, 1f32] {
+            assert!((a.nlerp(b, t) - a.slerp(b, t)).magnitude() < 0.00001f32);
+        }
+
+        // Opposite directions have no halfway direction
+        assert_eq!(a.nlerp(-a, 0.5f32), Vector3::default());
+    }
+
+    #[test]
+    fn test_v3_slerp() {
+        let a = Vector3::new(1f32, 0f32, 0f32);
+        let b = Vector3::new(0f32, 1f32, 0f32);
+
+        let half = core::f32::consts::FRAC_1_SQRT_2;
+        assert!((a.slerp(b, 0.5f32) - Vector3::new(half, half, 0f32)).magnitude() < 0.00001f32);
+
+        // Constant angular speed, a third of the way is 30 degrees
+        let third = a.slerp(b, 1f32 / 3f32);
+        assert!(approx_eq(third.dot(a), 30f32.to_radians().cos(), 0.00001f32));
+
+        assert_eq!(a.slerp(a, 0.5f32), a);
+    }
+
+    #[test]
+    fn test_v3_slerp_opposite() {
+        let a = Vector3::new(0f32, 0.6f32, 0.8f32);
+        let b = -a;
+
+        for i in 0 ..= 10 {
+            let t = i as f32 / 10f32;
+            let v = a.slerp(b, t);
+
+            assert!(approx_eq(v.magnitude(), 1f32, 0.0001f32), "{}", t);
+            assert!(approx_eq(v.dot(a), (t * core::f32::consts::PI).cos(), 0.0001f32), "{}", t);
+        }
+
+        // Halfway is perpendicular to both rather than zero
+        assert!(a.slerp(b, 0.5f32).dot(a).abs() < 0.0001f32);
+        assert!((a.slerp(b, 1f32) - b).magnitude() < 0.0001f32);
+    }
+
+    #[test]
+    fn test_v3_extend_truncate() {
+        let v = Vector3::new(1f32, 2f32, 3f32);
+
+        let h: Vector4 = v.extend(1f32);
+        assert_eq!(h, Vector4::new(1f32, 2f32, 3f32, 1f32));
+        assert_eq!(v.extend::<3>(5f32), v);
+
+        let t: Vector2 = v.truncate();
+        assert_eq!(t, Vector2::new(1f32, 2f32));
+        assert_eq!(h.truncate::<3>(), v);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_v3_extend_shrinking() {
+        let _ = Vector3::new(1f32, 2f32, 3f32).extend::<2>(0f32);
+    }
+
+    #[test]
+    fn test_v3_copysign() {
+        assert_eq!(Vector3::new(1f32, 2f32, 3f32).copysign(Vector3::new(-1f32, 1f32, -1f32)), Vector3::new(-1f32, 2f32, -3f32));
+        assert_eq!(Vector3::new(-1f32, -2f32, 3f32).copysign(Vector3::new(5f32, -0f32, 0f32)), Vector3::new(1f32, -2f32, 3f32));
+    }
+
+    #[test]
+    fn test_v3_quantize() {
+        let cell = 0.01f32;
+
+        let a = Vector3::new(1.0012f32, -2.0031f32, 0.5004f32);
+        let b = Vector3::new(1.0047f32, -2.0068f32, 0.5001f32);
+        let far = Vector3::new(1.0212f32, -2.0031f32, 0.5004f32);
+
+        assert_eq!(a.quantize(cell), [100, -201, 50]);
+        assert_eq!(a.quantize(cell), b.quantize(cell));
+        assert_ne!(a.quantize(cell), far.quantize(cell));
+
+        // Usable for welding
+        let mut welded = std::collections::HashMap::new();
+        for v in [a, b, far] {
+            welded.entry(v.quantize(cell)).or_insert(v);
+        }
+
+        assert_eq!(welded.len(), 2);
+    }
+
+    #[test]
+    fn test_v3_move_towards() {
+        let a = Vector3::default();
+        let far = Vector3::new(0f32, 3f32, 4f32);
+
+        let moved = a.move_towards(far, 2f32);
+        assert!((moved - Vector3::new(0f32, 1.2f32, 1.6f32)).magnitude() < 0.0001f32, "{}", moved);
+        assert!(((moved - a).magnitude() - 2f32).abs() < 0.0001f32);
+
+        // Within reach snaps exactly
+        assert_eq!(a.move_towards(far, 5f32), far);
+        assert_eq!(a.move_towards(far, 10f32), far);
+        assert_eq!(far.move_towards(far, 1f32), far);
+    }
+
+    #[test]
+    fn test_v3_approx_eq_ulps() {
+        let a = Vector3::new(1f32, -2f32, 1e-20f32);
+        let next = Vector3::new(f32::from_bits(1f32.to_bits() + 1), -2f32, 1e-20f32);
+
+        assert!(a.approx_eq_ulps(next, 1));
+        assert!(!a.approx_eq_ulps(next, 0));
+
+        let sum = Vector3::from_single(0.1f32) + Vector3::from_single(0.2f32);
+        assert!(sum.approx_eq_ulps(Vector3::from_single(0.3f32), 4));
+        assert!(!sum.approx_eq_ulps(Vector3::from_single(0.31f32), 4));
+
+        // Signed zeroes are the same, NaN is never equal
+        assert!(Vector3::from_single(0f32).approx_eq_ulps(Vector3::from_single(-0f32), 0));
+        assert!(!Vector3::from_single(f32::NAN).approx_eq_ulps(Vector3::from_single(f32::NAN), u32::MAX));
+    }
+
+    #[test]
+    fn test_v3_to_rgb_u8() {
+        assert_eq!(Vector3::new(1.5f32, -0.1f32, 0.5f32).to_rgb_u8(), [255, 0, 128]);
+        assert_eq!(Vector3::new(1f32, 0f32, 0.2f32).to_rgb_u8(), [255, 0, 51]);
+    }
+
+    #[test]
+    fn test_v3_generalized_cross() {
+        let a = Vector3::new(1f32, 2f32, 3f32);
+        let b = Vector3::new(-2f32, 0.5f32, 4f32);
+
+        let c = Vector3::generalized_cross(&[a, b]);
+
+        assert!((c - a.cross(b)).magnitude() < 0.0001f32, "{} != {}", c, a.cross(b));
+    }
+
+    #[test]
     fn test_v3_normalization() {
         let mut a = Vector3::from_single(1.0f32);
 
         // Test 4 times for sanity reasons!
         for _ in 0 .. 4 {
-            assert_eq!(a.normalize().magnitude(), 1f32);
+            assert!(approx_eq(a.normalize().magnitude(), 1f32, 0.00001f32), "{}", a);
             a *= 2f32;
         }
+
+        // Zero has no direction, normalizing in place shouldn't fill it with NaN
+        let mut zero = Vector3::default();
+        zero.normalize_mut();
+
+        assert_eq!(zero, Vector3::default());
+    }
+
+    #[test]
+    fn test_v3_norm_l1_linf() {
+        // The aliases forward to the canonical names
+        let a = Vector3::new(3f32, -4f32, 0f32);
+
+        assert_eq!(a.norm_l1(), a.l1_norm());
+        assert_eq!(a.norm_linf(), a.linf_norm());
+    }
+
+    #[test]
+    fn test_v3_wedge() {
+        let a = Vector3::new(1f32, 2f32, 3f32);
+        let b = Vector3::new(-2f32, 0.5f32, 4f32);
+
+        assert_eq!(a.wedge(b), a.cross(b));
+    }
+
+    #[test]
+    fn test_v3_mean() {
+        assert_eq!(Vector3::new(2f32, 4f32, 6f32).mean(), 4f32)
+    }
+
+    #[test]
+    fn test_v3_normalize_mut() {
+        let v = Vector3::new(1f32, 2f32, 3f32);
+        let mut n = v;
+
+        n.normalize_mut();
+
+        assert!((n.magnitude() - 1f32).abs() < 0.0001f32);
+        assert_eq!(n, v.normalize());
+
+        let mut zero = Vector3::default();
+        zero.normalize_mut();
+
+        assert_eq!(zero, Vector3::default());
+    }
+
+    #[test]
+    fn test_v3_normalize_fast() {
+        let vectors = [
+            Vector3::new(1f32, 0f32, 0f32),
+            Vector3::new(1f32, 1f32, 1f32),
+            Vector3::new(-3f32, 4f32, 12f32),
+            Vector3::new(0.001f32, -0.02f32, 0.3f32),
+            Vector3::new(250f32, 1000f32, -75f32),
+        ];
+
+        for v in vectors {
+            let exact = v.normalize();
+            let fast = v.normalize_fast();
+
+            for c in 0 .. 3 {
+                assert!((fast[c] - exact[c]).abs() <= exact[c].abs() * 0.001f32);
+            }
+        }
     }
 
     //
@@ -103,6 +433,79 @@ mod vector3 {
         assert_eq!(Vector3::from_array([2f32, 4f32, 8f32]) / 2f32, Vector3::from_array([1f32, 2f32, 4f32]))
     }
 
+    #[test]
+    fn test_v3_v3_outer() {
+        let m = Vector3::new(1f32, 2f32, 3f32).outer(Vector3::new(1f32, 0f32, 0f32));
+
+        assert_eq!(m.data, [[1f32, 0f32, 0f32], [2f32, 0f32, 0f32], [3f32, 0f32, 0f32]])
+    }
+
+    #[test]
+    fn test_v3_is_right_handed() {
+        let x = Vector3::new(1f32, 0f32, 0f32);
+        let y = Vector3::new(0f32, 1f32, 0f32);
+        let z = Vector3::new(0f32, 0f32, 1f32);
+
+        assert!(is_right_handed(x, y, z));
+        assert!(!is_right_handed(x, y, -z));
+        assert!(!is_right_handed(y, x, z));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_v3_le_bytes() {
+        let a = Vector3::new(1.5f32, -2f32, 1024f32);
+        let bytes = a.to_le_bytes();
+
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(&bytes[0 .. 4], &1.5f32.to_le_bytes());
+        assert_eq!(Vector3::from_le_bytes(&bytes), Some(a));
+        assert_eq!(Vector3::from_le_bytes(&bytes[0 .. 11]), None);
+    }
+
+    #[test]
+    fn test_v3_lex_cmp() {
+        use std::cmp::Ordering;
+
+        let a = Vector3::new(1f32, 2f32, 3f32);
+        let b = Vector3::new(1f32, 3f32, 0f32);
+        let nan = Vector3::new(1f32, f32::NAN, 0f32);
+
+        assert_eq!(a.lex_cmp(&b), Ordering::Less);
+        assert_eq!(b.lex_cmp(&a), Ordering::Greater);
+        assert_eq!(a.lex_cmp(&a), Ordering::Equal);
+
+        assert_eq!(b.lex_cmp(&nan), Ordering::Less);
+        assert_eq!(nan.lex_cmp(&nan), Ordering::Equal);
+
+        let mut sorted = [nan, b, a];
+        sorted.sort_by(|x, y| x.lex_cmp(y));
+
+        assert_eq!(sorted[0], a);
+        assert_eq!(sorted[1], b);
+    }
+
+    #[test]
+    fn test_v3_reflect() {
+        let a = Vector3::new(1f32, -2f32, 3f32);
+
+        assert_eq!(a.reflect(Vector3::new(0f32, 1f32, 0f32)), Vector3::new(1f32, 2f32, 3f32));
+        assert_eq!(a.reflect(Vector3::new(0f32, 0f32, -1f32)), Vector3::new(1f32, -2f32, -3f32));
+    }
+
+    #[test]
+    fn test_v3_refract() {
+        let normal = Vector3::new(0f32, 0f32, 1f32);
+        let incident = Vector3::new(0.6f32, 0f32, -0.8f32);
+
+        let eta = 0.75f32;
+        let refracted = incident.refract(normal, eta);
+
+        // Tangential part scales by eta, the rest keeps it unit length
+        let z = -(1f32 - (0.6f32 * eta) * (0.6f32 * eta)).sqrt();
+        assert!((refracted - Vector3::new(0.6f32 * eta, 0f32, z)).magnitude() < 0.0001f32);
+    }
+
     //
     // V3 from other vector tests
     //
@@ -121,4 +524,128 @@ mod vector3 {
 
         assert_eq!(v3, Vector3::from_array([1f32, 2f32, 3f32]))
     }
-}
\ No newline at end of file
+}
+
+mod vector4 {
+    use crate::math::vector::common::*;
+    use super::approx_eq;
+
+    #[test]
+    fn test_v4_aligned() {
+        assert_eq!(std::mem::align_of::<AlignedVector4>(), 16);
+        assert_eq!(std::mem::size_of::<AlignedVector4>(), 16);
+
+        let a = Vector4::new(1f32, -2f32, 3.5f32, 4f32);
+        let aligned = AlignedVector4::from(a);
+
+        assert_eq!(aligned[2], 3.5f32);
+        assert_eq!(Vector4::from(aligned), a);
+        assert_eq!((&aligned as *const AlignedVector4 as usize) % 16, 0);
+    }
+
+    #[test]
+    fn test_v4_to_rgba_u8() {
+        assert_eq!(Vector4::new(1.5f32, -0.1f32, 0.5f32, 1f32).to_rgba_u8(), [255, 0, 128, 255]);
+    }
+
+    #[test]
+    fn test_v4_normalization() {
+        let mut a = Vector4::new(1f32, -2f32, 3f32, 0.5f32);
+
+        // Test 4 times for sanity reasons!
+        for _ in 0 .. 4 {
+            assert!(approx_eq(a.normalize().magnitude(), 1f32, 0.00001f32), "{}", a);
+            a *= 2f32;
+        }
+
+        // Zero has no direction, normalizing in place shouldn't fill it with NaN
+        let mut zero = Vector4::default();
+        zero.normalize_mut();
+
+        assert_eq!(zero, Vector4::default());
+    }
+
+    #[test]
+    fn test_v4_to_array() {
+        let array = [1f32, 2f32, 3f32, 4f32];
+
+        assert_eq!(Vector4::from_array(array).to_array(), array);
+        assert_eq!(Vector4::from_array(array).as_slice(), &array);
+    }
+
+    #[test]
+    fn test_v4_as_mut_slice() {
+        let mut a = Vector4::default();
+
+        a.as_mut_slice().copy_from_slice(&[1f32, 2f32, 3f32, 4f32]);
+
+        assert_eq!(a.as_slice().len(), 4);
+        assert_eq!(a[0], 1f32);
+        assert_eq!(a[3], 4f32);
+    }
+
+    #[test]
+    fn test_v4_clamp_scalar() {
+        let a = Vector4::new(-2f32, 0.5f32, 3f32, 1f32);
+
+        assert_eq!(a.clamp_scalar(0f32, 1f32), Vector4::new(0f32, 0.5f32, 1f32, 1f32))
+    }
+
+    #[test]
+    fn test_v4_fold() {
+        let a = Vector4::new(1f32, -7f32, 3f32, 5f32);
+
+        assert_eq!(a.fold(0f32, |acc, c| acc.max(c.abs())), 7f32);
+        assert_eq!(a.fold(Vec::new(), |mut acc, c| { acc.push(c); acc }), [1f32, -7f32, 3f32, 5f32]);
+    }
+
+    #[test]
+    fn test_v4_get() {
+        let mut a = Vector4::new(1f32, 2f32, 3f32, 4f32);
+
+        assert_eq!(a.get(3), Some(&4f32));
+        assert_eq!(a.get(4), None);
+
+        *a.get_mut(0).unwrap() = 5f32;
+
+        assert_eq!(a[0], 5f32);
+        assert!(a.get_mut(4).is_none());
+    }
+}
+
+mod vectorn {
+    use crate::math::vector::Vector;
+
+    //
+    // Large vector operations
+    //
+    #[test]
+    fn test_vn_dot_kahan() {
+        // Every 1 is below the precision of an f32 holding 1e8, so naively they all vanish
+        let mut a = Vector::<f32, 1001>::from_single(1f32);
+        a[0] = 1e8f32;
+
+        let b = Vector::<f32, 1001>::from_single(1f32);
+
+        assert_eq!(a.dot(b), 1e8f32);
+        assert_eq!(a.dot_kahan(b), 100001000f32);
+    }
+
+    #[test]
+    fn test_vn_generalized_cross() {
+        let others = [
+            Vector::<f32, 5>::from_array([1f32, 0f32, 2f32, -1f32, 3f32]),
+            Vector::<f32, 5>::from_array([0f32, 4f32, 1f32, 2f32, -2f32]),
+            Vector::<f32, 5>::from_array([2f32, -1f32, 0f32, 1f32, 1f32]),
+            Vector::<f32, 5>::from_array([-3f32, 2f32, 1f32, 0f32, 0.5f32]),
+        ];
+
+        let n = Vector::generalized_cross(&others);
+
+        assert!(n.magnitude() > 1f32);
+
+        for v in others {
+            assert!(n.dot(v).abs() < 0.001f32, "{} isn't perpendicular to {}", n, v);
+        }
+    }
+}
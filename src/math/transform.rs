@@ -0,0 +1,49 @@
+//
+// Position, rotation and scale bundled together for scene graphs
+//
+
+use crate::math::component::MathDelegate;
+use crate::math::vector::common::*;
+use crate::math::matrix::common::Matrix3x3;
+
+/// 2D transform applied as scale, then rotation, then translation
+#[derive(Copy, Clone, Debug)]
+#[allow(dead_code)]
+pub struct Transform2D {
+    pub position: Vector2,
+
+    /// Counter clockwise rotation in radians
+    pub rotation: f32,
+
+    pub scale: Vector2,
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self { position: Vector2::default(), rotation: 0f32, scale: Vector2::from_single(1f32) }
+    }
+}
+
+impl Transform2D {
+    /// Returns the affine matrix of this transform, points are transformed as `(x, y, 1)` column vectors
+    #[allow(dead_code)]
+    pub fn to_matrix(self) -> Matrix3x3 {
+        let (s, c) = (self.rotation.sin_delegate(), self.rotation.cos_delegate());
+        let (sx, sy) = (self.scale[0], self.scale[1]);
+
+        Matrix3x3::from_array([
+            [c * sx, -s * sy, self.position[0]],
+            [s * sx, c * sy, self.position[1]],
+            [0f32, 0f32, 1f32],
+        ])
+    }
+
+    /// Moves a point from the local space of this transform into its parent's space
+    #[allow(dead_code)]
+    pub fn transform_point(&self, p: Vector2) -> Vector2 {
+        let m = self.to_matrix();
+        let v = Vector3::new(p[0], p[1], 1f32);
+
+        Vector2::new(Vector3::from_array(m[0]).dot(v), Vector3::from_array(m[1]).dot(v))
+    }
+}
@@ -1,11 +1,12 @@
 #![allow(unused)]
 #![allow(dead_code)]
 
-use super::component::Component;
+use super::component::{Component, MathDelegate};
+use super::matrix::Matrix;
 
-use std::ops::*;
-use std::cmp::*;
-use std::fmt::*;
+use core::ops::*;
+use core::cmp::*;
+use core::fmt::*;
 
 ///
 /// Configurable vector type for usage with Vector math
@@ -31,6 +32,59 @@ impl<T: Component, const COUNT: usize> Vector<T, COUNT> {
         Vector { data: [value; COUNT] }
     }
 
+    /// Returns a copy of the underlying array of this [Vector]
+    pub fn to_array(self) -> [T; COUNT] {
+        self.data
+    }
+
+    /// Returns the components of this [Vector] as a slice
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the components of this [Vector] as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Returns the component at the given index, or [None] if it's out of bounds
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.data.get(index)
+    }
+
+    /// Returns the component at the given index mutably, or [None] if it's out of bounds
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.data.get_mut(index)
+    }
+
+    /// Returns a longer [Vector] with the components of this one followed by `fill`
+    ///
+    /// Converting a point to homogeneous coordinates is `extend(1.0)`, the [From] conversions always fill with zero
+    ///
+    /// # Note:
+    ///     Panics if `M` is smaller than this [Vector], use [Vector::truncate] to shrink!
+    pub fn extend<const M: usize>(&self, fill: T) -> Vector<T, M> {
+        assert!(M >= COUNT, "can't extend a {} component vector to {} components", COUNT, M);
+
+        let mut v = Vector::<T, M>::from_single(fill);
+        v.data[.. COUNT].copy_from_slice(&self.data);
+
+        v
+    }
+
+    /// Returns a shorter [Vector] with the trailing components of this one dropped
+    ///
+    /// # Note:
+    ///     Panics if `M` is larger than this [Vector], use [Vector::extend] to grow!
+    pub fn truncate<const M: usize>(&self) -> Vector<T, M> {
+        assert!(M <= COUNT, "can't truncate a {} component vector to {} components", COUNT, M);
+
+        let mut v = Vector::<T, M>::default();
+        v.data.copy_from_slice(&self.data[.. M]);
+
+        v
+    }
+
     /// Returns the sum of all [VectorComponent]'s within this [Vector]
     pub fn sum(&self) -> T {
         let mut sum = T::default();
@@ -42,6 +96,56 @@ impl<T: Component, const COUNT: usize> Vector<T, COUNT> {
         sum
     }
 
+    /// Reduces the components of this [Vector] into a single value, visiting them in order
+    ///
+    /// For example, `fold(0f32, |acc, c| acc.max(c.abs()))` is the L-infinity norm
+    pub fn fold<B>(&self, init: B, f: impl Fn(B, T) -> B) -> B {
+        self.iter().fold(init, |acc, c| f(acc, *c))
+    }
+
+    /// Returns the mean of all [VectorComponent]'s within this [Vector]
+    pub fn mean(&self) -> T {
+        self.sum() / T::from_usize(COUNT)
+    }
+
+    /// Returns this [Vector] with every component raised to the integer power `n`
+    pub fn powi(&self, n: i32) -> Self {
+        Self::from_array(self.data.map(|c| c.powi_delegate(n)))
+    }
+
+    /// Returns the square root of every component, not to be confused with [Vector::magnitude]!
+    pub fn sqrt(&self) -> Self {
+        Self::from_array(self.data.map(|c| c.sqrt_delegate()))
+    }
+
+    /// Returns the cube root of every component, negative components stay negative
+    pub fn cbrt(&self) -> Self {
+        Self::from_array(self.data.map(|c| c.cbrt_delegate()))
+    }
+
+    /// Returns this [Vector] with the sign of each component taken from the matching component of `sign`
+    pub fn copysign(&self, sign: Self) -> Self {
+        let mut v = *self;
+
+        for c in 0 .. COUNT {
+            v[c] = self[c].copysign_delegate(sign[c]);
+        }
+
+        v
+    }
+
+    /// Returns the (population) variance of the components within this [Vector]
+    pub fn variance(&self) -> T {
+        let mean = self.mean();
+
+        self.fold(T::default(), |acc, c| acc + (c - mean) * (c - mean)) / T::from_usize(COUNT)
+    }
+
+    /// Returns the (population) standard deviation of the components within this [Vector]
+    pub fn std_dev(&self) -> T {
+        self.variance().sqrt_delegate()
+    }
+
     /// The length of this [Vector], not to be confused with [Vector::sum]!
     pub fn magnitude(&self) -> T {
         self.dot(*self).sqrt_delegate()
@@ -52,6 +156,46 @@ impl<T: Component, const COUNT: usize> Vector<T, COUNT> {
         *self / self.magnitude()
     }
 
+    /// Normalizes this [Vector] in place
+    ///
+    /// # Note:
+    ///     A zero length [Vector] has no direction, it is left untouched rather than filled with NaN
+    pub fn normalize_mut(&mut self) {
+        let m = self.magnitude();
+
+        if m == T::default() {
+            return;
+        }
+
+        *self /= m;
+    }
+
+    /// Returns an approximately normalized version of this [Vector]
+    ///
+    /// # Note:
+    ///     Uses a reciprocal square root approximation instead of a division, the result is within ~0.01% of [Vector::normalize]
+    ///     Prefer [Vector::normalize] when precision matters more than speed!
+    pub fn normalize_fast(&self) -> Self {
+        *self * self.dot(*self).inv_sqrt_fast_delegate()
+    }
+
+    /// Moves this [Vector] at most `max_delta` towards `target`, landing exactly on it once it's within reach
+    pub fn move_towards(&self, target: Self, max_delta: T) -> Self {
+        let delta = target - *self;
+        let distance = delta.magnitude();
+
+        if distance <= max_delta || distance == T::default() {
+            return target;
+        }
+
+        *self + delta * (max_delta / distance)
+    }
+
+    /// Linearly interpolates between this [Vector] and `rhs`, `t` of zero returns this [Vector] and one returns `rhs`
+    pub fn lerp(&self, rhs: Self, t: T) -> Self {
+        *self + (rhs - *self) * t
+    }
+
     /// Returns the dot product of this [Vector] and another
     pub fn dot(&self, rhs : Self) -> T {
         let mut d = T::default();
@@ -63,16 +207,255 @@ impl<T: Component, const COUNT: usize> Vector<T, COUNT> {
         d
     }
 
+    /// Returns a [Vector] perpendicular to all `COUNT - 1` of the given vectors, the generalization of the cross product
+    ///
+    /// Each component is the determinant of the vectors with that axis as the first row (a cofactor expansion)
+    /// In 3D, `Vector::generalized_cross(&[a, b])` is `a.cross(b)`
+    ///
+    /// # Note:
+    ///     Panics if `others` doesn't contain exactly `COUNT - 1` vectors!
+    pub fn generalized_cross(others: &[Self]) -> Self {
+        assert_eq!(others.len() + 1, COUNT, "Need COUNT - 1 vectors for a generalized cross product!");
+
+        let mut v = Self::default();
+
+        for c in 0 .. COUNT {
+            let mut m = Matrix::<T, COUNT, COUNT>::default();
+
+            m[0][c] = T::get_one();
+
+            for (row, other) in others.iter().enumerate() {
+                m[row + 1] = other.data;
+            }
+
+            v[c] = m.determinant_generic();
+        }
+
+        v
+    }
+
+    /// Returns the dot product of this [Vector] and another using compensated (Kahan-Babuška-Neumaier) summation
+    ///
+    /// # Note:
+    ///     Slower than [Vector::dot], only worth it for large `COUNT` where rounding error adds up
+    pub fn dot_kahan(&self, rhs: Self) -> T {
+        let mut sum = T::default();
+        let mut compensation = T::default();
+
+        for c in 0 .. COUNT {
+            let p = self[c] * rhs[c];
+            let t = sum + p;
+
+            // Recover the low order bits lost by whichever of the two terms is smaller
+            if sum.abs_delegate() >= p.abs_delegate() {
+                compensation += (sum - t) + p;
+            } else {
+                compensation += (p - t) + sum;
+            }
+
+            sum = t;
+        }
+
+        sum + compensation
+    }
+
+    /// Reflects this [Vector] about the given normal (which must be normalized)
+    pub fn reflect(&self, normal: Self) -> Self {
+        let two = T::get_one() + T::get_one();
+
+        *self - normal * (two * self.dot(normal))
+    }
+
+    /// Refracts this [Vector] (which must be normalized) through a surface with the given normal (which must be normalized)
+    ///
+    /// `eta` is the ratio of indices of refraction (outside / inside), on total internal reflection a zero [Vector] is returned
+    pub fn refract(&self, normal: Self, eta: T) -> Self {
+        let one = T::get_one();
+        let d = self.dot(normal);
+
+        let k = one - eta * eta * (one - d * d);
+
+        if k < T::default() {
+            return Self::default();
+        }
+
+        *self * eta - normal * (eta * d + k.sqrt_delegate())
+    }
+
+    /// Returns the outer product of this [Vector] and another, the complement to [Vector::dot]
+    ///
+    /// Each element of the resulting [Matrix] is `[y][x] = self[y] * rhs[x]`
+    pub fn outer<const WIDTH: usize>(&self, rhs: Vector<T, WIDTH>) -> Matrix<T, WIDTH, COUNT> {
+        let mut m = Matrix::<T, WIDTH, COUNT>::default();
+
+        for y in 0 .. COUNT {
+            for x in 0 .. WIDTH {
+                m[y][x] = self[y] * rhs[x];
+            }
+        }
+
+        m
+    }
+
+    /// Compares this [Vector] with another lexicographically (first component first)
+    ///
+    /// Gives a total order for sorting and ordered maps, NaN is ordered after every other value and equal to itself
+    pub fn lex_cmp(&self, other: &Self) -> Ordering {
+        for c in 0 .. COUNT {
+            let (a, b) = (self[c], other[c]);
+
+            #[allow(clippy::eq_op)]
+            let ordering = a.partial_cmp(&b).unwrap_or_else(|| (a != a).cmp(&(b != b)));
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        Ordering::Equal
+    }
+
     /// Returns a copy of this [Vector] with each component set to their absolute value
     pub fn abs(&self) -> Self {
         let mut a = *self;
 
-        for mut v in *a {
-            v = v.abs_delegate();
+        for v in a.iter_mut() {
+            *v = v.abs_delegate();
         }
 
         a
     }
+
+    /// Returns a copy of this [Vector] with the smaller of each component between this and another
+    pub fn min(&self, rhs: Self) -> Self {
+        let mut m = *self;
+
+        for c in 0 .. COUNT {
+            m[c] = m[c].min_delegate(rhs[c]);
+        }
+
+        m
+    }
+
+    /// Returns a copy of this [Vector] with the larger of each component between this and another
+    pub fn max(&self, rhs: Self) -> Self {
+        let mut m = *self;
+
+        for c in 0 .. COUNT {
+            m[c] = m[c].max_delegate(rhs[c]);
+        }
+
+        m
+    }
+
+    /// Returns a copy of this [Vector] with each component clamped between the matching components of `min` and `max`
+    pub fn clamp(&self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    /// Returns a copy of this [Vector] with every component clamped into `[min, max]`
+    pub fn clamp_scalar(&self, min: T, max: T) -> Self {
+        self.clamp(Self::from_single(min), Self::from_single(max))
+    }
+
+    /// Returns the largest component of this [Vector]
+    pub fn max_component(&self) -> T {
+        let mut m = self[0];
+
+        self.iter().for_each(|x| {
+            m = m.max_delegate(*x)
+        });
+
+        m
+    }
+
+    /// Returns the L1 (taxicab) norm of this [Vector], the sum of the absolute components
+    pub fn l1_norm(&self) -> T {
+        self.abs().sum()
+    }
+
+    /// Returns the L-infinity (maximum) norm of this [Vector], the largest absolute component
+    pub fn linf_norm(&self) -> T {
+        let mut m = T::default();
+
+        self.iter().for_each(|x| {
+            m = m.max_delegate(x.abs_delegate())
+        });
+
+        m
+    }
+
+    /// Alias of [Vector::l1_norm], named to sit alongside [Vector::distance_l1]
+    pub fn norm_l1(&self) -> T {
+        self.l1_norm()
+    }
+
+    /// Alias of [Vector::linf_norm], named to sit alongside [Vector::distance_linf]
+    pub fn norm_linf(&self) -> T {
+        self.linf_norm()
+    }
+
+    /// Returns the L1 (manhattan) distance between this [Vector] and another
+    pub fn distance_l1(&self, rhs: Self) -> T {
+        (*self - rhs).l1_norm()
+    }
+
+    /// Returns the L-infinity (chebyshev) distance between this [Vector] and another
+    pub fn distance_linf(&self, rhs: Self) -> T {
+        (*self - rhs).linf_norm()
+    }
+}
+
+//
+// Comparison
+//
+impl<const COUNT: usize> Vector<f32, COUNT> {
+    /// Returns true if every component is at most `max_ulps` representable floats away from the matching component of another [Vector]
+    ///
+    /// Unlike an absolute epsilon this scales with the magnitude of the components, NaN never compares equal
+    pub fn approx_eq_ulps(&self, rhs: Self, max_ulps: u32) -> bool {
+        // Reorders the bits of a float so consecutive floats are consecutive integers, -0 and 0 become the same
+        fn ordered(f: f32) -> i64 {
+            let bits = f.to_bits() as i32;
+
+            if bits < 0 {
+                i32::MIN as i64 - bits as i64
+            } else {
+                bits as i64
+            }
+        }
+
+        (0 .. COUNT).all(|c| {
+            let (a, b) = (self[c], rhs[c]);
+
+            !a.is_nan() && !b.is_nan() && (ordered(a) - ordered(b)).unsigned_abs() <= max_ulps as u64
+        })
+    }
+}
+
+//
+// Serialization
+//
+impl<const COUNT: usize> Vector<f32, COUNT> {
+    /// Returns the components of this [Vector] as little endian bytes, 4 bytes per component
+    #[cfg(feature = "alloc")]
+    pub fn to_le_bytes(self) -> alloc::vec::Vec<u8> {
+        self.iter().flat_map(|c| c.to_le_bytes()).collect()
+    }
+
+    /// Reads a [Vector] from little endian bytes (see [Vector::to_le_bytes])
+    ///
+    /// Returns [None] if there are fewer than `COUNT * 4` bytes, any extra bytes are ignored
+    pub fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut v = Self::default();
+
+        for c in 0 .. COUNT {
+            let chunk = bytes.get(c * 4 .. c * 4 + 4)?;
+            v[c] = f32::from_le_bytes(chunk.try_into().ok()?);
+        }
+
+        Some(v)
+    }
 }
 
 //
@@ -106,8 +489,8 @@ impl<T: Component, const COUNT: usize> DerefMut for Vector<T, COUNT> {
 // Formatting Traits
 //
 impl<T: Component, const COUNT: usize> Debug for Vector<T, COUNT> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Vector<{}, {}> {{\n", std::any::type_name::<T>(), COUNT).expect("Failed to write!");
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "Vector<{}, {}> {{", core::any::type_name::<T>(), COUNT).expect("Failed to write!");
 
         for c in 0 .. COUNT {
             writeln!(f, "\t[{}] = {}", c, self[c]).expect("Failed to write!");
@@ -120,7 +503,7 @@ impl<T: Component, const COUNT: usize> Debug for Vector<T, COUNT> {
 }
 
 impl<T: Component, const COUNT: usize> Display for Vector<T, COUNT> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "<").expect("Failed to write!");
 
         let mut first = true;
@@ -231,7 +614,7 @@ impl<T: Component, const COUNT: usize> Neg for Vector<T, COUNT> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        let mut d = self.clone();
+        let mut d = self;
 
         for c in 0 .. COUNT {
             d[c] = -d[c];
@@ -284,6 +667,8 @@ macro_rules! vector_from_vector {
 /// Contains commonly used [Vector] aliases with additional implementations for ease of use
 pub mod common {
     use super::*;
+    use crate::math::matrix::common::Matrix4x4;
+    use crate::math::component::Component;
 
     /// 2D Vector
     pub type Vector2 = Vector<f32, 2>;
@@ -294,6 +679,20 @@ pub mod common {
         pub fn new(x: T, y: T) -> Self {
             Self::from_array([x, y])
         }
+
+        /// Returns the wedge product of this [Vector] and another, in 2D that's the scalar "perp dot" product
+        ///
+        /// Positive when `rhs` is counter clockwise from this [Vector] (the 3D version returns the cross product)
+        pub fn wedge(&self, rhs: Self) -> T {
+            self[0] * rhs[1] - self[1] * rhs[0]
+        }
+
+        /// Rotates this [Vector] counter clockwise by `angle` radians
+        pub fn rotate(&self, angle: T) -> Self {
+            let (s, c) = (angle.sin_delegate(), angle.cos_delegate());
+
+            Self::new(self[0] * c - self[1] * s, self[0] * s + self[1] * c)
+        }
     }
 
     /// 3D Vector
@@ -315,6 +714,81 @@ pub mod common {
                 self[0] * rhs[1] - self[1] * rhs[0]
             ])
         }
+
+        /// Returns the point inside the box from `min` to `max` closest to this one, points inside are left untouched
+        pub fn clamp_to_aabb(&self, min: Self, max: Self) -> Self {
+            self.clamp(min, max)
+        }
+
+        /// Returns the wedge product of this [Vector] and another, in 3D that's the [Vector::cross] product
+        pub fn wedge(&self, rhs: Self) -> Self {
+            self.cross(rhs)
+        }
+    }
+
+    impl Vector3 {
+        /// Returns this [Vector] as an 8 bit color, components are clamped to `[0, 1]` and rounded to the nearest step
+        pub fn to_rgb_u8(self) -> [u8; 3] {
+            self.data.map(unit_to_u8)
+        }
+
+        /// Returns the coordinates of the grid cell of size `cell` this point falls in, usable as a `HashMap` key for welding vertices
+        ///
+        /// # Note:
+        ///     Points either side of a cell boundary land in different cells however close they are, check neighbouring cells if that matters
+        pub fn quantize(&self, cell: f32) -> [i64; 3] {
+            self.data.map(|c| (c / cell).floor_delegate() as i64)
+        }
+
+        /// Spherically interpolates between this direction and `rhs` at a constant angular speed, both must be normalized
+        ///
+        /// # Note:
+        ///     The great circle between (nearly) parallel or opposite directions is undefined!
+        ///     Parallel directions fall back to [Vector3::nlerp], opposite ones rotate about an arbitrary perpendicular axis
+        pub fn slerp(&self, rhs: Vector3, t: f32) -> Vector3 {
+            let d = Component::clamp(self.dot(rhs), -1f32, 1f32);
+            let theta = d.acos_delegate();
+            let sin_theta = theta.sin_delegate();
+
+            if sin_theta.abs() < 0.0001f32 {
+                if d > 0f32 {
+                    return self.nlerp(rhs, t);
+                }
+
+                // nlerp would pass through zero, swing around any axis perpendicular to this direction instead
+                let a = self.abs();
+                let basis = if a[0] <= a[1] && a[0] <= a[2] {
+                    Vector3::new(1f32, 0f32, 0f32)
+                } else if a[1] <= a[2] {
+                    Vector3::new(0f32, 1f32, 0f32)
+                } else {
+                    Vector3::new(0f32, 0f32, 1f32)
+                };
+
+                let axis = self.cross(basis).normalize();
+                let phi = t * theta;
+
+                return *self * phi.cos_delegate() + axis.cross(*self) * phi.sin_delegate();
+            }
+
+            (*self * ((1f32 - t) * theta).sin_delegate() + rhs * (t * theta).sin_delegate()) / sin_theta
+        }
+
+        /// Cheap approximation of [Vector3::slerp] that normalizes the linear interpolation, good enough for blending normals
+        ///
+        /// # Note:
+        ///     Opposite directions blend through zero halfway, which is returned as is rather than filled with NaN
+        pub fn nlerp(&self, rhs: Vector3, t: f32) -> Vector3 {
+            let mut v = self.lerp(rhs, t);
+            v.normalize_mut();
+
+            v
+        }
+    }
+
+    /// Returns true if the basis `a`, `b`, `c` is right handed
+    pub fn is_right_handed<T: Component>(a: Vector<T, 3>, b: Vector<T, 3>, c: Vector<T, 3>) -> bool {
+        a.cross(b).dot(c) > T::default()
     }
 
     /// 4D Vector (same type as [Quaternion])
@@ -328,10 +802,81 @@ pub mod common {
         }
     }
 
-    /// Quaternion (same type as [Vector4])
+    impl Vector4 {
+        /// Returns this [Vector] as an 8 bit color with alpha (see [Vector3::to_rgb_u8])
+        pub fn to_rgba_u8(self) -> [u8; 4] {
+            self.data.map(unit_to_u8)
+        }
+    }
+
+    /// [Vector4] aligned to 16 bytes so it can be loaded straight into a SIMD register
+    ///
+    /// Plain [Vector4] is only aligned like a single `f32`, convert to this at the edges of SIMD code
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    #[repr(C, align(16))]
+    pub struct AlignedVector4(pub Vector4);
+
+    impl From<Vector4> for AlignedVector4 {
+        fn from(v: Vector4) -> Self {
+            Self(v)
+        }
+    }
+
+    impl From<AlignedVector4> for Vector4 {
+        fn from(v: AlignedVector4) -> Self {
+            v.0
+        }
+    }
+
+    impl Deref for AlignedVector4 {
+        type Target = Vector4;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl DerefMut for AlignedVector4 {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+
+    /// Maps `[0, 1]` onto `[0, 255]`, rounding half up
+    fn unit_to_u8(c: f32) -> u8 {
+        (c.saturate() * 255f32 + 0.5f32) as u8
+    }
+
+    /// Quaternion (same type as [Vector4]), stored as `(x, y, z, w)` with `w` being the real part
     pub type Quaternion = Vector4;
 
     impl Quaternion {
+        /// Returns the quaternion that doesn't rotate anything
+        pub fn identity() -> Self {
+            Self::new(0f32, 0f32, 0f32, 1f32)
+        }
+
+        /// Returns the rotation of `angle` radians around `axis`, the axis is normalized internally
+        pub fn from_axis_angle(axis: Vector3, angle: f32) -> Self {
+            let half = angle * 0.5f32;
+            let a = axis.normalize() * half.sin_delegate();
+
+            Self::new(a[0], a[1], a[2], half.cos_delegate())
+        }
 
+        /// Returns the rotation matrix of this [Quaternion]
+        ///
+        /// # Note:
+        ///     Assumes the [Quaternion] is normalized!
+        pub fn to_matrix(self) -> Matrix4x4 {
+            let (x, y, z, w) = (self[0], self[1], self[2], self[3]);
+
+            Matrix4x4::from_array([
+                [1f32 - 2f32 * (y * y + z * z), 2f32 * (x * y - w * z), 2f32 * (x * z + w * y), 0f32],
+                [2f32 * (x * y + w * z), 1f32 - 2f32 * (x * x + z * z), 2f32 * (y * z - w * x), 0f32],
+                [2f32 * (x * z - w * y), 2f32 * (y * z + w * x), 1f32 - 2f32 * (x * x + y * y), 0f32],
+                [0f32, 0f32, 0f32, 1f32],
+            ])
+        }
     }
 }
\ No newline at end of file
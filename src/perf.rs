@@ -1 +1,6 @@
-pub mod scoped_stopwatch;
\ No newline at end of file
+pub mod scoped_stopwatch;
+pub mod profiler;
+pub mod fps_counter;
+
+#[cfg(test)]
+mod tests;
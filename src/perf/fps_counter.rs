@@ -0,0 +1,50 @@
+use std::io;
+
+//
+// Smoothed frames per second, ticked once a frame
+// Doesn't print anything itself, write it wherever is convenient (stdout, a status bar, nowhere)
+//
+
+/// How much of the previous average survives each tick by default
+pub const FPS_SMOOTHING: f32 = 0.9f32;
+
+pub struct FpsCounter {
+    /// How much of the previous average survives each tick, in `[0, 1)`, zero disables smoothing
+    pub smoothing: f32,
+
+    /// Exponential moving average of the frame time in seconds, [None] until the first tick
+    frame_time: Option<f32>,
+}
+
+impl Default for FpsCounter {
+    fn default() -> Self {
+        Self::new(FPS_SMOOTHING)
+    }
+}
+
+impl FpsCounter {
+    pub fn new(smoothing: f32) -> Self {
+        Self { smoothing, frame_time: None }
+    }
+
+    /// Records a frame that took `delta` seconds
+    pub fn tick(&mut self, delta: f32) {
+        self.frame_time = Some(match self.frame_time {
+            Some(average) => average * self.smoothing + delta * (1f32 - self.smoothing),
+            None => delta,
+        });
+    }
+
+    /// Returns the smoothed frames per second, zero before the first tick
+    pub fn fps(&self) -> f32 {
+        match self.frame_time {
+            Some(t) => 1f32 / t,
+            None => 0f32,
+        }
+    }
+
+    /// Writes the smoothed frames per second as a line of text
+    pub fn write_to(&self, out: &mut dyn io::Write) -> io::Result<()> {
+        writeln!(out, "Refresh ~= {:.1}", self.fps())
+    }
+}
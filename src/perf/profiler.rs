@@ -0,0 +1,77 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::time;
+
+//
+// Aggregates timings of named scopes across many calls (eg. every frame of the render loop)
+// Each thread has its own profiler, use ScopedStopwatch::new_profiled to record into it
+//
+
+/// Accumulated timings of every call to a single scope
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ScopeStats {
+    pub calls: u32,
+    pub total: time::Duration,
+}
+
+impl ScopeStats {
+    /// Returns the mean duration of a call to this scope
+    pub fn average(&self) -> time::Duration {
+        if self.calls == 0 {
+            return time::Duration::ZERO;
+        }
+
+        self.total / self.calls
+    }
+}
+
+#[derive(Default)]
+pub struct Profiler {
+    scopes: HashMap<String, ScopeStats>,
+}
+
+thread_local! {
+    static PROFILER: RefCell<Profiler> = RefCell::new(Profiler::default());
+}
+
+impl Profiler {
+    /// Adds a single call of the scope `id` taking `elapsed` to this thread's profiler
+    pub fn record(id: &str, elapsed: time::Duration) {
+        PROFILER.with_borrow_mut(|p| {
+            let stats = p.scopes.entry(id.to_string()).or_default();
+
+            stats.calls += 1;
+            stats.total += elapsed;
+        });
+    }
+
+    /// Returns what has been recorded for the scope `id` on this thread so far
+    #[allow(dead_code)]
+    pub fn stats(id: &str) -> Option<ScopeStats> {
+        PROFILER.with_borrow(|p| p.scopes.get(id).copied())
+    }
+
+    /// Forgets every scope recorded on this thread
+    #[allow(dead_code)]
+    pub fn reset() {
+        PROFILER.with_borrow_mut(|p| p.scopes.clear());
+    }
+
+    /// Returns a table of every scope recorded on this thread, the most expensive scopes come first
+    pub fn report() -> String {
+        PROFILER.with_borrow(|p| {
+            let mut scopes: Vec<(&String, &ScopeStats)> = p.scopes.iter().collect();
+            scopes.sort_by(|a, b| b.1.total.cmp(&a.1.total).then_with(|| a.0.cmp(b.0)));
+
+            let mut report = format!("{:<24} {:>8} {:>12} {:>12}", "SCOPE", "CALLS", "TOTAL (s)", "AVERAGE (s)");
+
+            for (id, stats) in scopes {
+                write!(report, "\n{:<24} {:>8} {:>12.6} {:>12.6}", id, stats.calls, stats.total.as_secs_f32(), stats.average().as_secs_f32())
+                    .expect("Failed to write!");
+            }
+
+            report
+        })
+    }
+}
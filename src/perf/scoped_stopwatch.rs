@@ -1,17 +1,20 @@
 use std::time;
+use super::profiler::Profiler;
 
 //
 // Stopwatch that when it exits scope (is dropped) prints the elapsed time
 // Use for one shot time keeping, when you don't need a record other than in stdout
+// Profiled stopwatches record into the Profiler instead of printing
 //
 pub struct ScopedStopwatch {
     start : Option<time::Instant>,
-    id : String
+    id : String,
+    profiled : bool
 }
 
 impl ScopedStopwatch {
     pub fn new(id: String) -> Self {
-        ScopedStopwatch { start: None, id }
+        ScopedStopwatch { start: None, id, profiled: false }
     }
 
     pub fn begin(&mut self) {
@@ -25,10 +28,25 @@ impl ScopedStopwatch {
 
         s
     }
+
+    /// Starts a stopwatch that adds its time to the [Profiler] when dropped, rather than printing it
+    pub fn new_profiled(id: String) -> Self {
+        let mut s = Self::new_begin(id);
+
+        s.profiled = true;
+
+        s
+    }
 }
 
 impl Drop for ScopedStopwatch {
     fn drop(&mut self) {
-        println!("STOPWATCH: {} took {} seconds", self.id, (time::Instant::now() - self.start.unwrap()).as_secs_f32());
+        let elapsed = time::Instant::now() - self.start.unwrap();
+
+        if self.profiled {
+            Profiler::record(&self.id, elapsed);
+        } else {
+            println!("STOPWATCH: {} took {} seconds", self.id, elapsed.as_secs_f32());
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,30 @@
+#![allow(unused)]
+
+use crate::perf::fps_counter::*;
+
+#[test]
+fn test_fps_counter_output() {
+    let mut counter = FpsCounter::new(0.5f32);
+    let mut out: Vec<u8> = Vec::new();
+
+    counter.write_to(&mut out).unwrap();
+
+    // 40ms then 10ms averages to 25ms, 40 frames per second
+    counter.tick(0.04f32);
+    counter.write_to(&mut out).unwrap();
+
+    counter.tick(0.01f32);
+    counter.write_to(&mut out).unwrap();
+
+    assert_eq!(String::from_utf8(out).unwrap(), "Refresh ~= 0.0\nRefresh ~= 25.0\nRefresh ~= 40.0\n");
+}
+
+#[test]
+fn test_fps_counter_unsmoothed() {
+    let mut counter = FpsCounter::new(0f32);
+
+    counter.tick(0.5f32);
+    counter.tick(0.25f32);
+
+    assert_eq!(counter.fps(), 4f32);
+}
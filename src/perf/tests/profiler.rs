@@ -0,0 +1,39 @@
+#![allow(unused)]
+
+use std::time::Duration;
+use crate::perf::profiler::*;
+use crate::perf::scoped_stopwatch::ScopedStopwatch;
+
+#[test]
+fn test_profiler_aggregates_by_id() {
+    Profiler::record("march", Duration::from_millis(3));
+    Profiler::record("march", Duration::from_millis(5));
+    Profiler::record("shade", Duration::from_millis(1));
+
+    let march = Profiler::stats("march").unwrap();
+
+    assert_eq!(march.calls, 2);
+    assert_eq!(march.total, Duration::from_millis(8));
+    assert_eq!(march.average(), Duration::from_millis(4));
+
+    // Most expensive scope first
+    let report = Profiler::report();
+    let lines: Vec<&str> = report.lines().collect();
+
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].starts_with("march"));
+    assert!(lines[2].starts_with("shade"));
+}
+
+#[test]
+fn test_profiled_stopwatch_records() {
+    for _ in 0 .. 2 {
+        let _s = ScopedStopwatch::new_profiled("frame".to_string());
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    let frame = Profiler::stats("frame").unwrap();
+
+    assert_eq!(frame.calls, 2);
+    assert!(frame.total >= Duration::from_millis(2));
+}
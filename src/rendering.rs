@@ -1,3 +1,15 @@
 pub mod shader;
 
-pub mod viewport;
\ No newline at end of file
+pub mod viewport;
+pub mod camera;
+pub mod orbit_camera;
+pub mod raymarch;
+pub mod sdf;
+pub mod color;
+pub mod colormap;
+pub mod framebuffer;
+pub mod accumulator;
+pub mod shading;
+
+#[cfg(test)]
+mod tests;
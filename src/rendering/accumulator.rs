@@ -0,0 +1,62 @@
+//
+// Averages many frames of the same image into one, jittering each frame gives supersampling over time
+//
+
+use crate::rendering::color::Color;
+use crate::rendering::framebuffer::Framebuffer;
+
+#[allow(dead_code)]
+pub struct Accumulator {
+    /// Sum of every frame added so far
+    pub sum: Framebuffer<Color>,
+
+    /// Number of frames added so far
+    pub count: usize,
+}
+
+impl Accumulator {
+    #[allow(dead_code)]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { sum: Framebuffer::new(width, height), count: 0 }
+    }
+
+    /// Adds a frame to the running total
+    ///
+    /// # Note:
+    ///     The frame must be the same size as the [Accumulator]!
+    #[allow(dead_code)]
+    pub fn add_frame(&mut self, fb: &Framebuffer<Color>) {
+        assert!(fb.width == self.sum.width && fb.height == self.sum.height, "Frame size doesn't match the accumulator!");
+
+        for (sum, pixel) in self.sum.pixels.iter_mut().zip(&fb.pixels) {
+            *sum += *pixel;
+        }
+
+        self.count += 1;
+    }
+
+    /// Returns the average of every frame added so far, black if there are none
+    #[allow(dead_code)]
+    pub fn resolve(&self) -> Framebuffer<Color> {
+        let mut fb = Framebuffer::new(self.sum.width, self.sum.height);
+
+        if self.count == 0 {
+            return fb;
+        }
+
+        let weight = 1f32 / self.count as f32;
+
+        for (pixel, sum) in fb.pixels.iter_mut().zip(&self.sum.pixels) {
+            *pixel = *sum * weight;
+        }
+
+        fb
+    }
+
+    /// Forgets every frame, call this when the image changes (eg. the camera moved)
+    #[allow(dead_code)]
+    pub fn reset(&mut self) {
+        self.sum.clear(Color::default());
+        self.count = 0;
+    }
+}
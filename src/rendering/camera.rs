@@ -0,0 +1,49 @@
+//
+// Camera lens models layered on top of the pinhole Viewport
+//
+
+use crate::math::vector::common::*;
+use crate::math::matrix::common::Matrix4x4;
+use crate::math::ray::Ray3D;
+use crate::rendering::viewport::Viewport;
+
+/// Thin lens used for depth of field, anything off the focal plane gets blurred
+#[allow(dead_code)]
+pub struct ThinLens {
+    /// Radius of the lens aperture in world units, zero behaves like a pinhole
+    pub aperture_radius: f32,
+
+    /// Distance from the camera to the plane that's in perfect focus
+    pub focal_distance: f32,
+}
+
+impl Viewport {
+    /// Returns a ray leaving a random point of the lens through the given pixel (see [Viewport::screen_to_world_ray])
+    ///
+    /// `lens_sample` picks the point on the aperture, both components should be uniformly random in `[0, 1)`
+    /// Averaging many samples per pixel produces the depth of field blur
+    #[allow(dead_code)]
+    pub fn generate_ray(&self, pixel: Vector2, size: Vector2, lens: &ThinLens, lens_sample: Vector2) -> Ray3D {
+        let pinhole = self.screen_to_world_ray(pixel, size);
+
+        if lens.aperture_radius == 0f32 {
+            return pinhole;
+        }
+
+        let rotation = Matrix4x4::rotation(self.euler);
+        let right = Vector3::from(rotation * Vector4::new(1f32, 0f32, 0f32, 0f32));
+        let up = Vector3::from(rotation * Vector4::new(0f32, 1f32, 0f32, 0f32));
+        let forward = Vector3::from(rotation * Vector4::new(0f32, 0f32, -1f32, 0f32));
+
+        // Every ray through this pixel converges where the pinhole ray meets the focal plane
+        let focal_point = pinhole.origin + pinhole.direction * (lens.focal_distance / pinhole.direction.dot(forward));
+
+        // Uniformly distributed point on the aperture disc
+        let r = lens.aperture_radius * lens_sample[0].sqrt();
+        let theta = lens_sample[1] * 2f32 * std::f32::consts::PI;
+
+        let origin = pinhole.origin + right * (r * theta.cos()) + up * (r * theta.sin());
+
+        Ray3D::new(origin, (focal_point - origin).normalize())
+    }
+}
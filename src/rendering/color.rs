@@ -0,0 +1,24 @@
+//
+// Color helpers, colors are linear RGB stored in a Vector3
+//
+
+use crate::math::vector::common::Vector3;
+
+/// Linear RGB color
+pub type Color = Vector3;
+
+/// Gamma used to encode linear values for display
+pub const DISPLAY_GAMMA: f32 = 2.2f32;
+
+/// Rec. 709 luma weights
+const LUMINANCE_WEIGHTS: [f32; 3] = [0.2126f32, 0.7152f32, 0.0722f32];
+
+/// Returns the relative luminance of a linear RGB color using Rec. 709 weights
+pub fn luminance(color: Vector3) -> f32 {
+    color.dot(Vector3::from_array(LUMINANCE_WEIGHTS))
+}
+
+/// Encodes a linear value with [DISPLAY_GAMMA] so it's perceptually uniform
+pub fn gamma_encode(linear: f32) -> f32 {
+    linear.max(0f32).powf(1f32 / DISPLAY_GAMMA)
+}
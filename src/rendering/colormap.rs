@@ -0,0 +1,61 @@
+//
+// Colormaps for visualizing scalar fields (SDF distance, march step counts, ...)
+//
+// Each map takes `t` in `[0, 1]` (values outside are clamped) and returns a linear RGB color with components in `[0, 1]`
+//
+
+use crate::math::component::Component;
+use crate::rendering::color::Color;
+
+/// Evaluates a polynomial with one coefficient per power of `t` for each channel
+#[allow(dead_code)]
+fn polynomial<const N: usize>(t: f32, coefficients: [[f32; 3]; N]) -> Color {
+    let mut c = Color::default();
+
+    for k in coefficients.iter().rev() {
+        c = c * t + Color::from_array(*k);
+    }
+
+    c.clamp_scalar(0f32, 1f32)
+}
+
+/// Black to white
+#[allow(dead_code)]
+pub fn grayscale(t: f32) -> Color {
+    Color::from_single(t.saturate())
+}
+
+/// Polynomial fit of matplotlib's viridis, dark purple through teal to yellow
+///
+/// `viridis(0)` is `(0.2777, 0.0054, 0.3341)` and `viridis(1)` is `(0.9869, 0.9064, 0.1313)`
+///
+/// Fit from https://www.shadertoy.com/view/WlfXRN (CC0)
+#[allow(dead_code)]
+pub fn viridis(t: f32) -> Color {
+    polynomial(t.saturate(), [
+        [0.277_727_33, 0.005_407_345, 0.334_099_8],
+        [0.105_093_04, 1.404_613_5, 1.384_590_1],
+        [-0.330_861_83, 0.214_847_56, 0.095_095_16],
+        [-4.634_230_6, -5.799_101, -19.332_441],
+        [6.228_27, 14.179_933, 56.690_55],
+        [4.776_385, -13.745_145, -65.353_035],
+        [-5.435_456, 4.645_852_6, 26.312_435],
+    ])
+}
+
+/// Polynomial fit of Google's turbo, dark blue through green to dark red
+///
+/// `turbo(0)` is `(0.1357, 0.0914, 0.1067)` and `turbo(1)` is `(0.5659, 0.0504, 0.0)`
+///
+/// Fit from https://gist.github.com/mikhailov-work/0d177465a8151eb6ede1768d51d476c7 (Apache 2.0)
+#[allow(dead_code)]
+pub fn turbo(t: f32) -> Color {
+    polynomial(t.saturate(), [
+        [0.135_721_38, 0.091_402_61, 0.106_673_3],
+        [4.615_392_6, 2.194_188_4, 12.641_946],
+        [-42.660_32, 4.842_966_6, -60.582_05],
+        [132.131_08, -14.185_033, 110.362_77],
+        [-152.942_38, 4.277_299, -89.903_11],
+        [59.286_38, 2.829_566, 27.348_25],
+    ])
+}
@@ -0,0 +1,50 @@
+use std::ops::*;
+
+///
+/// A 2D grid of pixels backed by a single [Vec]
+///
+/// Pixels are stored row by row, starting from the top left
+///
+pub struct Framebuffer<T: Copy + Default> {
+    pub width: usize,
+    pub height: usize,
+
+    /// The underlying pixel storage, `width * height` elements long
+    pub pixels: Vec<T>,
+}
+
+impl<T: Copy + Default> Framebuffer<T> {
+    /// Creates a new [Framebuffer] with every pixel set to the default value
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { width, height, pixels: vec![T::default(); width * height] }
+    }
+
+    /// Sets every pixel to the given value without reallocating
+    #[allow(dead_code)]
+    pub fn clear(&mut self, value: T) {
+        self.pixels.fill(value);
+    }
+
+    /// Returns each row of pixels along with its y index, from the top down
+    ///
+    /// The rows don't overlap, so they can be handed out to separate threads and written at the same time
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = (usize, &mut [T])> {
+        // chunks_mut panics on zero sized chunks, an empty framebuffer has no pixels to chunk anyway
+        self.pixels.chunks_mut(self.width.max(1)).enumerate()
+    }
+}
+
+/// Indexes the [Framebuffer] by `(x, y)`
+impl<T: Copy + Default> Index<(usize, usize)> for Framebuffer<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.pixels[y * self.width + x]
+    }
+}
+
+impl<T: Copy + Default> IndexMut<(usize, usize)> for Framebuffer<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self.pixels[y * self.width + x]
+    }
+}
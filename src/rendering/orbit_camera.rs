@@ -0,0 +1,68 @@
+use crate::math::vector::common::Vector3;
+use crate::math::matrix::common::Matrix4x4;
+use crate::rendering::viewport::Viewport;
+
+/// How close to straight up / down the camera may pitch, stops the view from flipping over the poles
+#[allow(dead_code)]
+const PITCH_LIMIT: f32 = std::f32::consts::FRAC_PI_2 - 0.01f32;
+
+/// Closest the camera may get to its target
+#[allow(dead_code)]
+const MIN_DISTANCE: f32 = 0.01f32;
+
+///
+/// Camera that orbits around a target point on a sphere
+///
+/// Yaw rotates around the Y axis, pitch raises the camera above (positive) or below (negative) the target
+///
+#[allow(dead_code)]
+pub struct OrbitCamera {
+    pub target: Vector3,
+    pub distance: f32,
+
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+impl OrbitCamera {
+    /// Rotates the camera around the target, pitch is clamped to avoid flipping over the poles
+    #[allow(dead_code)]
+    pub fn orbit(&mut self, dyaw: f32, dpitch: f32) {
+        self.yaw += dyaw;
+        self.pitch = (self.pitch + dpitch).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    }
+
+    /// Moves the camera away from (positive) or towards (negative) the target
+    #[allow(dead_code)]
+    pub fn zoom(&mut self, delta: f32) {
+        self.distance = (self.distance + delta).max(MIN_DISTANCE);
+    }
+
+    /// Returns the position of the camera on the orbit sphere
+    #[allow(dead_code)]
+    pub fn eye(&self) -> Vector3 {
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+
+        self.target + Vector3::new(sin_yaw * cos_pitch, sin_pitch, cos_yaw * cos_pitch) * self.distance
+    }
+
+    /// Returns a [Viewport] positioned on the orbit sphere looking at the target
+    #[allow(dead_code)]
+    pub fn to_viewport(&self) -> Viewport {
+        // Pitch then yaw, decomposed into the XYZ euler order used by the viewport
+        let r = Matrix4x4::rotate_y(self.yaw) * Matrix4x4::rotate_x(-self.pitch);
+
+        let euler = Vector3::new(
+            (-r[1][2]).atan2(r[2][2]),
+            r[0][2].clamp(-1f32, 1f32).asin(),
+            (-r[0][1]).atan2(r[0][0])
+        );
+
+        Viewport {
+            position: self.eye(),
+            euler,
+            ..Viewport::default()
+        }
+    }
+}
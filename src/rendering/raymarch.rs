@@ -0,0 +1,289 @@
+//
+// SDF raymarcher that draws the scene as ASCII art
+//
+
+use crate::math::component::Component;
+use crate::math::vector::common::*;
+use crate::rendering::viewport::Viewport;
+use crate::rendering::color::*;
+use crate::rendering::framebuffer::Framebuffer;
+use crate::rendering::sdf::Sdf;
+
+/// Characters used to draw intensity, from darkest to brightest
+/// http://paulbourke.net/dataformats/asciiart/
+pub const ASCII_RAMP: &str = " .'`^\",:;Il!i><~+_-?][}{1)(|\\/tfjrxnuvczXYUJCLQ0OZmwqpdbkhao*#MW&8%B@$";
+
+/// Furthest distance a ray will travel before it's considered a miss
+pub const MAX_DISTANCE: f32 = 10.0f32;
+
+/// Most steps a ray will take before giving up, grazing rays can otherwise crawl along a surface for a long time
+pub const MAX_STEPS: usize = 128;
+
+/// Distance to the surface that's considered a hit
+pub const SURFACE_EPSILON: f32 = 0.001f32;
+
+/// Width / height of a terminal character cell, characters are roughly twice as tall as they are wide
+pub const ASCII_PIXEL_ASPECT: f32 = 0.5f32;
+
+/// Default offset used when sampling the scene to estimate normals
+pub const NORMAL_EPSILON: f32 = 0.01f32;
+
+/// Estimates the normal of the scene at the given point using the tetrahedron technique
+/// https://iquilezles.org/articles/normalsSDF/
+pub fn normal_sdf<F: Fn(Vector3) -> f32>(scene: &F, s: Vector3) -> Vector3 {
+    normal_sdf_scaled(scene, s, NORMAL_EPSILON)
+}
+
+/// Same as [normal_sdf] but samples the scene `footprint` away from the point
+///
+/// Pass the size of a pixel at the hit distance to smooth out detail smaller than a pixel, reducing normal aliasing
+pub fn normal_sdf_scaled<F: Fn(Vector3) -> f32>(scene: &F, s: Vector3, footprint: f32) -> Vector3 {
+    let e = Vector3::from_single(footprint);
+
+    const X: f32 = 1.0f32;
+    const Y: f32 = -1.0f32;
+
+    let k1 = Vector3::from_array([X, Y, Y]);
+    let k2 = Vector3::from_array([Y, Y, X]);
+    let k3 = Vector3::from_array([Y, X, Y]);
+    let k4 = Vector3::from_array([X, X, X]);
+
+    k1 * scene(s + k1 * e) +
+        k2 * scene(s + k2 * e) +
+        k3 * scene(s + k3 * e) +
+        k4 * scene(s + k4 * e)
+}
+
+/// How marching a single ray ended
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MarchResult {
+    /// The ray reached the surface at this point
+    Hit(Vector3),
+
+    /// The ray travelled further than [Raymarcher::max_distance] without reaching anything
+    Missed,
+
+    /// The ray ran out of [Raymarcher::max_steps] before hitting or missing
+    GaveUp,
+}
+
+/// Marches rays through a scene described by a signed distance function
+pub struct Raymarcher {
+    /// Furthest distance a ray will travel before it's considered a miss
+    pub max_distance: f32,
+
+    /// Most steps a ray will take before it gives up
+    pub max_steps: usize,
+
+    /// Distance to the surface that's considered a hit
+    pub surface_epsilon: f32,
+
+    /// Scales [Raymarcher::surface_epsilon] by the distance travelled, so distant surfaces aren't refined past what a pixel can show
+    ///
+    /// Pixels cover more of the scene the further away they look (a cone), the threshold grows to match
+    pub cone_epsilon: bool,
+
+    /// Width / height of a single pixel, used alongside the framebuffer size to work out the aspect ratio
+    pub pixel_aspect: f32,
+}
+
+impl Default for Raymarcher {
+    fn default() -> Self {
+        Self { max_distance: MAX_DISTANCE, max_steps: MAX_STEPS, surface_epsilon: SURFACE_EPSILON, cone_epsilon: false, pixel_aspect: 1f32 }
+    }
+}
+
+impl Raymarcher {
+    /// Marches a single ray through the scene, reporting where it hit or why it didn't
+    pub fn march<S: Sdf + ?Sized>(&self, origin: Vector3, direction: Vector3, sdf: &S) -> MarchResult {
+        let mut t = 0.0f32;
+
+        for _ in 0 .. self.max_steps {
+            if t >= self.max_distance {
+                return MarchResult::Missed;
+            }
+
+            let s = origin + direction * t;
+            let r = sdf.distance(s);
+
+            let epsilon = if self.cone_epsilon {
+                self.surface_epsilon * t
+            } else {
+                self.surface_epsilon
+            };
+
+            if r < epsilon {
+                return MarchResult::Hit(s);
+            }
+
+            t += r;
+        }
+
+        // The last step may have carried the ray out of range
+        if t >= self.max_distance {
+            return MarchResult::Missed;
+        }
+
+        MarchResult::GaveUp
+    }
+
+    /// Marches a single ray and shades whatever it hit, misses are black
+    ///
+    /// The result is continuous, nothing is quantized until it's drawn (see [AsciiRamp])
+    pub fn trace<S: Sdf + ?Sized>(&self, origin: Vector3, direction: Vector3, sdf: &S) -> Color {
+        match self.march(origin, direction, sdf) {
+            MarchResult::Hit(s) => {
+                let n = sdf.normal(s);
+                let v = (origin - s).normalize();
+
+                Color::from_single(n.dot(v).max(0f32))
+            }
+
+            MarchResult::Missed | MarchResult::GaveUp => Color::default()
+        }
+    }
+
+    /// Renders the scene from the [Viewport] into the [Framebuffer], overwriting every pixel
+    ///
+    /// The [Framebuffer] is written in place, callers rendering every frame should keep one around and reuse it
+    pub fn render_into<S: Sdf + ?Sized>(&self, viewport: &Viewport, sdf: &S, fb: &mut Framebuffer<Color>) {
+        self.render_with(viewport, fb, |origin, direction| self.trace(origin, direction, sdf));
+    }
+
+    /// Renders the distance from the camera to the scene into the [Framebuffer] without shading, for shadow maps and the like
+    ///
+    /// Pixels that don't hit anything are set to the far plane of the [Viewport]
+    #[allow(dead_code)]
+    pub fn render_depth<S: Sdf + ?Sized>(&self, viewport: &Viewport, sdf: &S, depth: &mut Framebuffer<f32>) {
+        self.render_with(viewport, depth, |origin, direction| {
+            match self.march(origin, direction, sdf) {
+                MarchResult::Hit(s) => (s - origin).magnitude(),
+                MarchResult::Missed | MarchResult::GaveUp => viewport.far_plane
+            }
+        });
+    }
+
+    /// Fills every pixel of the [Framebuffer] with whatever `pixel` returns for the ray leaving the camera through it
+    fn render_with<T: Copy + Default>(&self, viewport: &Viewport, fb: &mut Framebuffer<T>, mut pixel: impl FnMut(Vector3, Vector3) -> T) {
+        let mat_v = viewport.view_matrix();
+        let aspect = fb.width as f32 / fb.height as f32 * self.pixel_aspect;
+        let mat_p = viewport.projection_matrix(aspect);
+
+        let mat_vp_i = (mat_p * mat_v).inverse();
+
+        let origin = viewport.position;
+
+        let (width, height) = (fb.width, fb.height);
+
+        for (y, row) in fb.rows_mut() {
+            let v = y as f32 / (height - 1).max(1) as f32;
+            let persp_y = -((v - 0.5f32) * 2f32);
+
+            for (x, out) in row.iter_mut().enumerate() {
+                let u = x as f32 / (width - 1).max(1) as f32;
+                let persp_x = (u - 0.5f32) * 2f32;
+
+                let direction = (mat_vp_i.unproject(Vector3::new(persp_x, persp_y, 0f32)) - origin).normalize();
+
+                *out = pixel(origin, direction);
+            }
+        }
+    }
+}
+
+/// Maps continuous colors onto a ramp of characters, this is the only place the image gets quantized
+pub struct AsciiRamp {
+    /// Characters from darkest to brightest
+    pub chars: Vec<char>,
+}
+
+impl Default for AsciiRamp {
+    fn default() -> Self {
+        Self::new(ASCII_RAMP)
+    }
+}
+
+impl AsciiRamp {
+    pub fn new(ramp: &str) -> Self {
+        Self { chars: ramp.chars().collect() }
+    }
+
+    /// Returns the character closest to the brightness of `color`
+    pub fn quantize(&self, color: Color) -> char {
+        // Ramp characters are perceptually spaced, so index by the gamma encoded luminance
+        let l = gamma_encode(luminance(color));
+
+        let m = (self.chars.len() - 1) as f32;
+        let c = (l.saturate() * m).round() as usize;
+
+        self.chars[c]
+    }
+}
+
+/// Converts a [Framebuffer] into ASCII art using [ASCII_RAMP]
+///
+/// Rows are separated by `\n`, the output contains no terminal escapes
+pub fn framebuffer_to_ascii(fb: &Framebuffer<Color>) -> String {
+    let mut output = String::with_capacity((fb.width + 1) * fb.height);
+    let ramp = AsciiRamp::default();
+
+    for y in 0 .. fb.height {
+        for x in 0 .. fb.width {
+            output.push(ramp.quantize(fb[(x, y)]));
+        }
+
+        if y != fb.height - 1 {
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+/// Renders scenes as ASCII art of a fixed size
+#[allow(dead_code)]
+pub struct AsciiRenderer {
+    pub width: usize,
+    pub height: usize,
+
+    pub raymarcher: Raymarcher,
+}
+
+impl AsciiRenderer {
+    #[allow(dead_code)]
+    pub fn new(width: usize, height: usize) -> Self {
+        let raymarcher = Raymarcher { pixel_aspect: ASCII_PIXEL_ASPECT, ..Raymarcher::default() };
+
+        Self { width, height, raymarcher }
+    }
+
+    /// Renders the scene from the [Viewport] as ASCII art
+    ///
+    /// Rows are separated by `\n`, the output contains no terminal escapes and is fully deterministic
+    #[allow(dead_code)]
+    pub fn render_to_string<S: Sdf + ?Sized>(&self, viewport: &Viewport, sdf: &S) -> String {
+        let mut fb = Framebuffer::new(self.width, self.height);
+
+        self.raymarcher.render_into(viewport, sdf, &mut fb);
+
+        framebuffer_to_ascii(&fb)
+    }
+}
+
+/// Renders the scene from the [Viewport] into a `width` x `height` block of ASCII art (see [AsciiRenderer::render_to_string])
+#[allow(dead_code)]
+pub fn render_scene_to_string<S: Sdf>(width: usize, height: usize, viewport: &Viewport, scene: S) -> String {
+    AsciiRenderer::new(width, height).render_to_string(viewport, &scene)
+}
+
+/// Renders the scene from the [Viewport] into a `width` x `height` 8 bit grayscale image, row by row from the top left
+///
+/// Intensities are gamma encoded for display, like the ASCII output
+#[allow(dead_code)]
+pub fn render_grayscale<S: Sdf>(width: usize, height: usize, viewport: &Viewport, scene: S) -> Vec<u8> {
+    let mut fb = Framebuffer::new(width, height);
+
+    Raymarcher::default().render_into(viewport, &scene, &mut fb);
+
+    fb.pixels.iter().map(|c| Color::from_single(gamma_encode(luminance(*c))).to_rgb_u8()[0]).collect()
+}
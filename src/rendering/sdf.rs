@@ -0,0 +1,278 @@
+//
+// Signed distance field shapes that can be composed into a scene
+// The distance formulas themselves live in crate::math::sdf
+//
+
+use crate::math::vector::common::{Vector3, Vector4};
+use crate::math::matrix::common::Matrix4x4;
+use crate::math::sdf;
+use crate::rendering::raymarch::normal_sdf;
+
+/// A shape described by a signed distance field
+pub trait Sdf {
+    /// Returns the signed distance from `p` to the surface, negative inside the shape
+    fn distance(&self, p: Vector3) -> f32;
+
+    /// Returns the surface normal at `p`, estimated from [Sdf::distance] unless the shape knows better
+    fn normal(&self, p: Vector3) -> Vector3 {
+        normal_sdf(&|p| self.distance(p), p).normalize()
+    }
+
+    /// Returns the material id of the surface closest to `p`, what an id means is up to the renderer
+    #[allow(dead_code)]
+    fn material(&self, _p: Vector3) -> u32 {
+        0
+    }
+}
+
+/// Plain distance functions are shapes too
+impl<F: Fn(Vector3) -> f32> Sdf for F {
+    fn distance(&self, p: Vector3) -> f32 {
+        self(p)
+    }
+}
+
+//
+// Primitives, all centered on the origin
+//
+
+#[allow(dead_code)]
+pub struct Sphere {
+    pub radius: f32,
+}
+
+impl Sdf for Sphere {
+    fn distance(&self, p: Vector3) -> f32 {
+        sdf::sphere(p, self.radius)
+    }
+
+    /// The gradient of a sphere points straight away from its center, no need to sample it
+    fn normal(&self, p: Vector3) -> Vector3 {
+        p.normalize()
+    }
+}
+
+/// Torus lying on the XZ plane
+#[allow(dead_code)]
+pub struct Torus {
+    /// Radius of the ring
+    pub major_radius: f32,
+
+    /// Radius of the tube
+    pub minor_radius: f32,
+}
+
+impl Sdf for Torus {
+    fn distance(&self, p: Vector3) -> f32 {
+        sdf::torus(p, (self.major_radius, self.minor_radius))
+    }
+}
+
+#[allow(dead_code)]
+pub struct Box {
+    pub half_extents: Vector3,
+}
+
+impl Sdf for Box {
+    fn distance(&self, p: Vector3) -> f32 {
+        sdf::cuboid(p, self.half_extents)
+    }
+}
+
+/// Box whose edges are rounded by `radius`, the rounding stays within the half extents
+#[allow(dead_code)]
+pub struct RoundedBox {
+    pub half_extents: Vector3,
+    pub radius: f32,
+}
+
+impl Sdf for RoundedBox {
+    fn distance(&self, p: Vector3) -> f32 {
+        sdf::rounded_cuboid(p, self.half_extents, self.radius)
+    }
+}
+
+/// Line segment from `a` to `b` with a thickness of `radius`
+#[allow(dead_code)]
+pub struct Capsule {
+    pub a: Vector3,
+    pub b: Vector3,
+    pub radius: f32,
+}
+
+impl Sdf for Capsule {
+    fn distance(&self, p: Vector3) -> f32 {
+        sdf::capsule(p, self.a, self.b, self.radius)
+    }
+}
+
+/// Capped cylinder standing along the Y axis
+#[allow(dead_code)]
+pub struct Cylinder {
+    /// Half of the height of the cylinder
+    pub half_height: f32,
+    pub radius: f32,
+}
+
+impl Sdf for Cylinder {
+    fn distance(&self, p: Vector3) -> f32 {
+        sdf::cylinder(p, self.half_height, self.radius)
+    }
+}
+
+//
+// Combinators
+//
+
+/// Union of two shapes, the surface closest to the point wins
+#[allow(dead_code)]
+pub struct Union<A: Sdf, B: Sdf> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A: Sdf, B: Sdf> Sdf for Union<A, B> {
+    fn distance(&self, p: Vector3) -> f32 {
+        self.a.distance(p).min(self.b.distance(p))
+    }
+
+    fn material(&self, p: Vector3) -> u32 {
+        if self.a.distance(p) <= self.b.distance(p) {
+            self.a.material(p)
+        } else {
+            self.b.material(p)
+        }
+    }
+}
+
+/// Polynomial smooth minimum used to blend shapes together, see [smooth_min]
+/// https://iquilezles.org/articles/smin/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum SmoothKind {
+    /// Cheapest, the blend has a continuous first derivative
+    Quadratic,
+
+    /// Slightly more expensive, the blend also has a continuous second derivative which shades smoother
+    Cubic,
+}
+
+/// Quadratic smooth minimum of `a` and `b`, the two blend where they're within `k` of each other
+#[allow(dead_code)]
+pub fn smooth_min_quadratic(a: f32, b: f32, k: f32) -> f32 {
+    if k <= 0f32 {
+        return a.min(b);
+    }
+
+    let h = (k - (a - b).abs()).max(0f32) / k;
+
+    a.min(b) - h * h * k * 0.25f32
+}
+
+/// Cubic smooth minimum of `a` and `b`, the two blend where they're within `k` of each other
+#[allow(dead_code)]
+pub fn smooth_min(a: f32, b: f32, k: f32) -> f32 {
+    if k <= 0f32 {
+        return a.min(b);
+    }
+
+    let h = (k - (a - b).abs()).max(0f32) / k;
+
+    a.min(b) - h * h * h * k * (1f32 / 6f32)
+}
+
+/// Union of two shapes that melts them together where they meet
+#[allow(dead_code)]
+pub struct SmoothUnion<A: Sdf, B: Sdf> {
+    pub a: A,
+    pub b: B,
+
+    /// Size of the blend region, zero is a sharp union
+    pub k: f32,
+
+    pub kind: SmoothKind,
+}
+
+impl<A: Sdf, B: Sdf> Sdf for SmoothUnion<A, B> {
+    fn distance(&self, p: Vector3) -> f32 {
+        let (a, b) = (self.a.distance(p), self.b.distance(p));
+
+        match self.kind {
+            SmoothKind::Quadratic => smooth_min_quadratic(a, b, self.k),
+            SmoothKind::Cubic => smooth_min(a, b, self.k),
+        }
+    }
+
+    fn material(&self, p: Vector3) -> u32 {
+        if self.a.distance(p) <= self.b.distance(p) {
+            self.a.material(p)
+        } else {
+            self.b.material(p)
+        }
+    }
+}
+
+/// Places a child shape in the scene by transforming the domain it's evaluated in
+///
+/// # Note:
+///     Only rotation, translation and uniform scale keep the distance correct!
+#[allow(dead_code)]
+pub struct Transformed<S: Sdf> {
+    pub child: S,
+
+    /// World to local space of the child, the inverse of the transform placing the child in the scene
+    pub inverse: Matrix4x4,
+}
+
+impl<S: Sdf> Transformed<S> {
+    /// Places the child with the given local to world transform
+    #[allow(dead_code)]
+    pub fn new(child: S, transform: Matrix4x4) -> Self {
+        Self { child, inverse: transform.inverse() }
+    }
+}
+
+impl<S: Sdf> Sdf for Transformed<S> {
+    fn distance(&self, p: Vector3) -> f32 {
+        let local = Vector3::from(self.inverse * Vector4::new(p[0], p[1], p[2], 1f32));
+
+        // Distances are measured in local space, undo the scale to bring them back into world space
+        let scale = Vector3::new(self.inverse[0][0], self.inverse[1][0], self.inverse[2][0]).magnitude();
+
+        self.child.distance(local) / scale
+    }
+
+    fn material(&self, p: Vector3) -> u32 {
+        self.child.material(Vector3::from(self.inverse * Vector4::new(p[0], p[1], p[2], 1f32)))
+    }
+}
+
+//
+// Domain folding, evaluate a shape at a folded point to mirror it
+// https://iquilezles.org/articles/sdfrepetition/
+//
+
+/// Reflects `p` across the plane perpendicular to `axis` so it always lands on the positive side
+///
+/// Shapes evaluated at the folded point appear mirrored across that plane
+#[allow(dead_code)]
+pub fn fold_axis(p: Vector3, axis: usize) -> Vector3 {
+    let mut p = p;
+    p[axis] = p[axis].abs();
+
+    p
+}
+
+/// Reflects `p` across the plane through the origin with the given unit `normal` so it always lands on the side `normal` points to
+///
+/// [fold_axis] is the special case of an axis aligned `normal`
+#[allow(dead_code)]
+pub fn fold_plane(p: Vector3, normal: Vector3) -> Vector3 {
+    let d = p.dot(normal);
+
+    if d < 0f32 {
+        p - normal * (2f32 * d)
+    } else {
+        p
+    }
+}
@@ -6,6 +6,7 @@
 /// # Notes
 ///     [ShaderType::Vertex] can be used without a corresponding [ShaderType::Fragment] unit in a [ShaderProgram] but there are exceptions, reference [ShaderProgram] for more info!
 #[repr(C)]
+#[allow(dead_code)]
 pub enum ShaderType {
     /// Provides per-vertex shading that is interpolated over the triangle
     Vertex,
@@ -14,6 +15,7 @@ pub enum ShaderType {
     Fragment,
 }
 
+#[allow(dead_code)]
 pub trait Shader {
     fn request_info();
 
@@ -0,0 +1,56 @@
+//
+// Helpers for shading surfaces
+//
+
+use crate::math::vector::common::Vector3;
+
+/// Interpolates a per-vertex attribute (normal, color, ...) across a triangle
+///
+/// `u` and `v` are the barycentric weights of `b` and `c`, as returned by [crate::math::ray::Ray3D::intersect_triangle]
+#[allow(dead_code)]
+pub fn interpolate_attribute(u: f32, v: f32, a: Vector3, b: Vector3, c: Vector3) -> Vector3 {
+    a * (1f32 - u - v) + b * u + c * v
+}
+
+/// Directions leaving a surface that both reflects and refracts light, see [fresnel]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[allow(dead_code)]
+pub struct Fresnel {
+    /// Mirror reflection of the incident direction
+    pub reflected: Vector3,
+
+    /// Incident direction bent through the surface, zero on total internal reflection (see [crate::math::vector::Vector::refract])
+    pub refracted: Vector3,
+
+    /// Fraction of light that's reflected, the rest is refracted
+    pub reflectance: f32,
+}
+
+/// Splits light hitting a surface into its reflected and refracted parts, weighted with Schlick's approximation
+/// https://en.wikipedia.org/wiki/Schlick%27s_approximation
+///
+/// `incident` and `normal` must be normalized, `eta` is the ratio of indices of refraction (outside / inside)
+#[allow(dead_code)]
+pub fn fresnel(incident: Vector3, normal: Vector3, eta: f32) -> Fresnel {
+    let reflected = incident.reflect(normal);
+    let refracted = incident.refract(normal, eta);
+
+    // Total internal reflection, nothing gets through
+    if refracted == Vector3::default() {
+        return Fresnel { reflected, refracted, reflectance: 1f32 };
+    }
+
+    let r0 = (1f32 - eta) / (1f32 + eta);
+    let r0 = r0 * r0;
+
+    // Going into a less dense medium the transmitted angle is the larger one, Schlick needs that one
+    let cos_theta = if eta > 1f32 {
+        -refracted.dot(normal)
+    } else {
+        -incident.dot(normal)
+    };
+
+    let reflectance = r0 + (1f32 - r0) * (1f32 - cos_theta).powi(5);
+
+    Fresnel { reflected, refracted, reflectance }
+}
@@ -0,0 +1,30 @@
+pub mod raymarch;
+pub mod color;
+pub mod orbit_camera;
+pub mod sdf;
+pub mod viewport;
+pub mod colormap;
+pub mod shading;
+pub mod accumulator;
+pub mod camera;
+pub mod framebuffer;
+
+use crate::math::vector::common::Vector3;
+use crate::rendering::viewport::Viewport;
+
+/// Camera looking straight down -Z at the origin from a short distance, the golden renders are drawn from here
+pub fn front_viewport() -> Viewport {
+    Viewport {
+        position: Vector3::new(0f32, 0f32, 2.5f32),
+        ..Viewport::default()
+    }
+}
+
+/// Camera that's moved and tilted off every axis, so projection tests don't pass by accident of symmetry
+pub fn test_viewport() -> Viewport {
+    Viewport {
+        position: Vector3::new(1f32, 2f32, 5f32),
+        euler: Vector3::new(0.1f32, -0.2f32, 0f32),
+        ..Viewport::default()
+    }
+}
@@ -0,0 +1,32 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::rendering::accumulator::Accumulator;
+use crate::rendering::color::Color;
+use crate::rendering::framebuffer::Framebuffer;
+
+#[test]
+fn test_accumulator_averages_frames() {
+    let mut accumulator = Accumulator::new(3, 2);
+
+    let mut a = Framebuffer::new(3, 2);
+    a.clear(Color::new(1f32, 0f32, 0.5f32));
+
+    let mut b = Framebuffer::new(3, 2);
+    b.clear(Color::new(0f32, 1f32, 0.25f32));
+
+    accumulator.add_frame(&a);
+    accumulator.add_frame(&b);
+
+    let resolved = accumulator.resolve();
+
+    assert_eq!(accumulator.count, 2);
+    assert!(resolved.pixels.iter().all(|p| *p == Color::new(0.5f32, 0.5f32, 0.375f32)));
+}
+
+#[test]
+fn test_accumulator_empty_is_black() {
+    let accumulator = Accumulator::new(2, 2);
+
+    assert!(accumulator.resolve().pixels.iter().all(|p| *p == Color::default()));
+}
@@ -0,0 +1,44 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::rendering::camera::ThinLens;
+use crate::rendering::viewport::Viewport;
+use crate::rendering::tests::test_viewport;
+
+#[test]
+fn test_generate_ray_pinhole() {
+    let viewport = test_viewport();
+    let size = Vector2::new(64f32, 32f32);
+    let pixel = Vector2::new(10f32, 20f32);
+
+    let lens = ThinLens { aperture_radius: 0f32, focal_distance: 3f32 };
+    let ray = viewport.generate_ray(pixel, size, &lens, Vector2::new(0.7f32, 0.3f32));
+    let pinhole = viewport.screen_to_world_ray(pixel, size);
+
+    assert_eq!(ray.origin, pinhole.origin);
+    assert_eq!(ray.direction, pinhole.direction);
+}
+
+#[test]
+fn test_generate_ray_converges_on_focal_plane() {
+    let viewport = test_viewport();
+    let size = Vector2::new(64f32, 32f32);
+    let pixel = Vector2::new(32f32, 16f32);
+
+    // The center pixel looks straight down the view axis, so the focal point is focal_distance along the pinhole ray
+    let lens = ThinLens { aperture_radius: 0.25f32, focal_distance: 3f32 };
+    let pinhole = viewport.screen_to_world_ray(pixel, size);
+    let focal_point = pinhole.origin + pinhole.direction * 3f32;
+
+    for sample in [Vector2::new(0.1f32, 0.2f32), Vector2::new(0.9f32, 0.6f32), Vector2::new(0.5f32, 0.95f32)] {
+        let ray = viewport.generate_ray(pixel, size, &lens, sample);
+
+        assert!((ray.origin - pinhole.origin).magnitude() <= 0.25f32 + 0.0001f32);
+
+        // Distance from the focal point to the line of the ray
+        let to_focus = focal_point - ray.origin;
+        let miss = (to_focus - ray.direction * to_focus.dot(ray.direction)).magnitude();
+
+        assert!(miss < 0.001f32, "{} missed the focal point by {}", sample, miss);
+    }
+}
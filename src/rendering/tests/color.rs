@@ -0,0 +1,16 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::rendering::color::*;
+
+#[test]
+fn test_luminance_green() {
+    let l = luminance(Vector3::new(0f32, 1f32, 0f32));
+
+    assert!((l - 0.7152f32).abs() < 0.0001f32)
+}
+
+#[test]
+fn test_luminance_white() {
+    assert!((luminance(Vector3::from_single(1f32)) - 1f32).abs() < 0.0001f32)
+}
@@ -0,0 +1,31 @@
+#![allow(unused)]
+
+use crate::rendering::color::Color;
+use crate::rendering::colormap::*;
+
+fn assert_close(a: Color, b: Color) {
+    assert!((a - b).magnitude() < 0.001f32, "{} != {}", a, b);
+}
+
+#[test]
+fn test_colormap_endpoints() {
+    assert_close(grayscale(0f32), Color::new(0f32, 0f32, 0f32));
+    assert_close(grayscale(1f32), Color::new(1f32, 1f32, 1f32));
+
+    assert_close(viridis(0f32), Color::new(0.2777f32, 0.0054f32, 0.3341f32));
+    assert_close(viridis(1f32), Color::new(0.9869f32, 0.9064f32, 0.1313f32));
+
+    assert_close(turbo(0f32), Color::new(0.1357f32, 0.0914f32, 0.1067f32));
+    assert_close(turbo(1f32), Color::new(0.5659f32, 0.0504f32, 0f32));
+}
+
+#[test]
+fn test_colormap_range() {
+    for i in -10 ..= 110 {
+        let t = i as f32 / 100f32;
+
+        for c in [grayscale(t), viridis(t), turbo(t)] {
+            assert!(c.iter().all(|x| (0f32 ..= 1f32).contains(x)), "{} out of range at {}", c, t);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+#![allow(unused)]
+
+use crate::rendering::framebuffer::Framebuffer;
+
+#[test]
+fn test_rows_mut() {
+    let mut fb = Framebuffer::<u32>::new(3, 2);
+
+    for (y, row) in fb.rows_mut() {
+        assert_eq!(row.len(), 3);
+
+        for (x, p) in row.iter_mut().enumerate() {
+            *p = (y * 10 + x) as u32;
+        }
+    }
+
+    assert_eq!(fb.pixels, vec![0, 1, 2, 10, 11, 12]);
+    assert_eq!(fb[(2, 1)], 12);
+}
+
+#[test]
+fn test_rows_mut_empty() {
+    let mut fb = Framebuffer::<u32>::new(0, 4);
+
+    assert_eq!(fb.rows_mut().count(), 0);
+}
@@ -0,0 +1,61 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::rendering::orbit_camera::OrbitCamera;
+
+fn test_camera() -> OrbitCamera {
+    OrbitCamera {
+        target: Vector3::new(1f32, 2f32, 3f32),
+        distance: 5f32,
+
+        yaw: 0.3f32,
+        pitch: 0.4f32,
+    }
+}
+
+#[test]
+fn test_orbit_full_turn() {
+    let mut camera = test_camera();
+    let start = camera.eye();
+
+    for _ in 0 .. 8 {
+        camera.orbit(std::f32::consts::TAU / 8f32, 0f32);
+    }
+
+    assert!((camera.eye() - start).magnitude() < 0.0001f32);
+}
+
+#[test]
+fn test_orbit_pitch_clamped() {
+    let mut camera = test_camera();
+    camera.orbit(0f32, 10f32);
+
+    assert!(camera.pitch < std::f32::consts::FRAC_PI_2);
+    assert!((camera.eye() - camera.target).magnitude() - camera.distance < 0.0001f32);
+}
+
+#[test]
+fn test_zoom() {
+    let mut camera = test_camera();
+
+    camera.zoom(-2f32);
+    assert_eq!(camera.distance, 3f32);
+
+    camera.zoom(-10f32);
+    assert!(camera.distance > 0f32);
+}
+
+#[test]
+fn test_to_viewport_target_in_view() {
+    for (yaw, pitch) in [(0f32, 0f32), (0.3f32, 0.4f32), (2.5f32, -1.2f32), (-1f32, 1.5f32)] {
+        let camera = OrbitCamera { yaw, pitch, ..test_camera() };
+        let viewport = camera.to_viewport();
+
+        let clip = viewport.projection_matrix(1f32) * viewport.view_matrix() * Vector4::new(1f32, 2f32, 3f32, 1f32);
+
+        // In front of the camera and in the center of the screen
+        assert!(clip[3] > 0f32);
+        assert!((clip[0] / clip[3]).abs() < 0.0001f32);
+        assert!((clip[1] / clip[3]).abs() < 0.0001f32);
+    }
+}
@@ -0,0 +1,189 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::rendering::viewport::Viewport;
+use crate::rendering::tests::front_viewport;
+use crate::rendering::raymarch::*;
+use crate::rendering::framebuffer::Framebuffer;
+use crate::rendering::color::Color;
+
+fn sphere_scene(p: Vector3) -> f32 {
+    p.magnitude() - 0.4f32
+}
+
+#[test]
+fn test_render_sphere_to_string() {
+    let expected = [
+        "                 ",
+        "                 ",
+        "      QwpqQ      ",
+        "    ZaW8%8WoZ    ",
+        "   nkW%@$@%Wkn   ",
+        "    ZoW8%8WaZ    ",
+        "      QqpwQ      ",
+        "                 ",
+        "                 ",
+    ].join("\n");
+
+    assert_eq!(render_scene_to_string(17, 9, &front_viewport(), sphere_scene), expected)
+}
+
+#[test]
+fn test_render_into_reuses_framebuffer() {
+    let raymarcher = Raymarcher::default();
+    let mut fb = Framebuffer::new(9, 5);
+
+    raymarcher.render_into(&front_viewport(), &sphere_scene, &mut fb);
+
+    let pixels = fb.pixels.as_ptr();
+    assert!(fb[(4, 2)][0] > 0f32);
+
+    // Nothing to hit, every pixel from the first render should be overwritten
+    raymarcher.render_into(&front_viewport(), &|_: Vector3| f32::MAX, &mut fb);
+
+    assert_eq!(fb.pixels.as_ptr(), pixels);
+    assert!(fb.pixels.iter().all(|p| *p == Color::default()));
+}
+
+#[test]
+fn test_normal_sdf_scaled_smooths_detail() {
+    // Sphere with high frequency bumps much smaller than the larger footprint
+    let bumpy = |p: Vector3| p.magnitude() - 1f32 + 0.005f32 * (p[0] * 80f32).sin() * (p[1] * 80f32).sin() * (p[2] * 80f32).sin();
+
+    let error = |footprint: f32| {
+        let mut total = 0f32;
+
+        for i in 0 .. 16 {
+            let a = i as f32 * 0.37f32;
+            let p = Vector3::new(a.cos() * 0.8f32, a.sin() * 0.6f32, 0.3f32).normalize();
+
+            let n = normal_sdf_scaled(&bumpy, p, footprint).normalize();
+            total += 1f32 - n.dot(p);
+        }
+
+        total
+    };
+
+    assert!(error(0.2f32) < error(0.001f32));
+}
+
+#[test]
+fn test_ascii_renderer_small_sphere() {
+    let renderer = AsciiRenderer::new(9, 5);
+
+    let expected = [
+        "         ",
+        "   0p0   ",
+        "  hB$Bh  ",
+        "   0p0   ",
+        "         ",
+    ].join("\n");
+
+    let frame = renderer.render_to_string(&front_viewport(), &sphere_scene);
+
+    assert_eq!(frame, expected);
+    assert_eq!(frame, renderer.render_to_string(&front_viewport(), &sphere_scene));
+}
+
+#[test]
+fn test_trace_is_continuous() {
+    let raymarcher = Raymarcher::default();
+    let viewport = front_viewport();
+
+    // Head on the sphere faces the camera, so it's fully lit
+    let lit = raymarcher.trace(viewport.position, Vector3::new(0f32, 0f32, -1f32), &sphere_scene);
+    assert!((lit[0] - 1f32).abs() < 0.0001f32, "{}", lit);
+
+    // The ramp is what buckets it, nearby intensities collapse onto the same character
+    let ramp = AsciiRamp::default();
+    let dimmed = lit * 0.999f32;
+
+    assert_ne!(dimmed, lit);
+    assert_eq!(ramp.quantize(dimmed), ramp.quantize(lit));
+    assert_eq!(ramp.quantize(lit), '$');
+}
+
+#[test]
+fn test_render_dyn_sdf() {
+    use crate::math::matrix::common::Matrix4x4;
+    use crate::rendering::sdf::{Sdf, Sphere, Transformed};
+
+    // Shape structs and plain functions render the same, Transformed keeps the estimated normal of the plain function
+    let scene: &dyn Sdf = &Transformed::new(Sphere { radius: 0.4f32 }, Matrix4x4::identity());
+
+    assert_eq!(AsciiRenderer::new(17, 9).render_to_string(&front_viewport(), scene), render_scene_to_string(17, 9, &front_viewport(), sphere_scene));
+}
+
+#[test]
+fn test_march_gives_up_on_grazing_rays() {
+    use crate::rendering::sdf::{Sdf, Torus};
+
+    // Skims just under the top of the tube where the ring is tangent to the ray, so every step is tiny
+    let torus = Torus { major_radius: 1f32, minor_radius: 0.05f32 };
+    let origin = Vector3::new(-3f32, 0.04f32, 1f32);
+    let direction = Vector3::new(1f32, 0f32, 0f32);
+
+    let impatient = Raymarcher { max_steps: 4, ..Raymarcher::default() };
+    assert_eq!(impatient.march(origin, direction, &torus), MarchResult::GaveUp);
+
+    let patient = Raymarcher { max_steps: 10000, ..Raymarcher::default() };
+    assert!(matches!(patient.march(origin, direction, &torus), MarchResult::Hit(_)));
+
+    // Nothing in the way at all
+    assert_eq!(patient.march(origin, Vector3::new(-1f32, 0f32, 0f32), &torus), MarchResult::Missed);
+}
+
+#[test]
+fn test_render_depth() {
+    let raymarcher = Raymarcher::default();
+    let viewport = front_viewport();
+    let mut depth = Framebuffer::new(17, 9);
+
+    raymarcher.render_depth(&viewport, &sphere_scene, &mut depth);
+
+    // The front of the sphere bulges towards the camera, so its center is closer than towards its silhouette
+    let center = depth[(8, 4)];
+    let edge = depth[(7, 4)];
+
+    assert!((center - 2.1f32).abs() < 0.01f32, "{}", center);
+    assert!(edge < viewport.far_plane);
+    assert!(center < edge, "{} >= {}", center, edge);
+
+    // Misses are pushed to the far plane
+    assert_eq!(depth[(0, 0)], viewport.far_plane);
+}
+
+#[test]
+fn test_march_cone_epsilon() {
+    let far_sphere = |p: Vector3| (p - Vector3::new(0f32, 0f32, -8f32)).magnitude() - 1f32;
+    let near_sphere = |p: Vector3| (p - Vector3::new(0f32, 0f32, -1f32)).magnitude() - 0.5f32;
+
+    // Almost tangent to the far sphere, a constant epsilon crawls along the silhouette
+    let grazing = Vector3::new(0.124f32.sin(), 0f32, -0.124f32.cos());
+
+    let constant = Raymarcher { max_steps: 16, ..Raymarcher::default() };
+    let cone = Raymarcher { cone_epsilon: true, ..constant };
+
+    assert_eq!(constant.march(Vector3::default(), grazing, &far_sphere), MarchResult::GaveUp);
+    assert!(matches!(cone.march(Vector3::default(), grazing, &far_sphere), MarchResult::Hit(_)));
+
+    // Up close the threshold is tiny, so the hit is still accurate
+    match cone.march(Vector3::default(), Vector3::new(0.3f32, 0.2f32, -1f32).normalize(), &near_sphere) {
+        MarchResult::Hit(s) => assert!(near_sphere(s).abs() < 0.002f32, "{}", near_sphere(s)),
+        result => panic!("{:?}", result)
+    }
+}
+
+#[test]
+fn test_render_grayscale() {
+    let image = render_grayscale(17, 9, &front_viewport(), sphere_scene);
+
+    assert_eq!(image.len(), 17 * 9);
+
+    // Facing the camera head on is fully lit, nothing is around the edges
+    assert!(image[4 * 17 + 8] > 250);
+
+    for corner in [0, 16, 8 * 17, 8 * 17 + 16] {
+        assert_eq!(image[corner], 0);
+    }
+}
@@ -0,0 +1,204 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::math::matrix::common::*;
+use crate::rendering::sdf::*;
+
+fn assert_surface<S: Sdf>(shape: &S, surface: &[Vector3], outside: &[Vector3]) {
+    for p in surface {
+        assert!(shape.distance(*p).abs() < 0.0001f32, "{} should be on the surface", p);
+    }
+
+    for p in outside {
+        assert!(shape.distance(*p) > 0f32, "{} should be outside", p);
+    }
+}
+
+#[test]
+fn test_box() {
+    let shape = Box { half_extents: Vector3::new(1f32, 2f32, 3f32) };
+
+    assert_surface(&shape,
+        &[Vector3::new(1f32, 0f32, 0f32), Vector3::new(0.5f32, -2f32, 1f32), Vector3::new(1f32, 2f32, 3f32)],
+        &[Vector3::new(1.5f32, 0f32, 0f32), Vector3::new(0f32, 0f32, -4f32), Vector3::new(2f32, 3f32, 4f32)]
+    );
+}
+
+#[test]
+fn test_rounded_box() {
+    let shape = RoundedBox { half_extents: Vector3::new(1f32, 1f32, 1f32), radius: 0.25f32 };
+
+    // Faces stay put, corners are pulled in
+    let corner = Vector3::from_single(0.75f32) + Vector3::from_single(0.25f32 / 3f32.sqrt());
+
+    assert_surface(&shape,
+        &[Vector3::new(1f32, 0f32, 0f32), Vector3::new(0f32, -1f32, 0.5f32), corner],
+        &[Vector3::new(1f32, 1f32, 1f32), Vector3::new(0f32, 0f32, 1.1f32)]
+    );
+}
+
+#[test]
+fn test_capsule() {
+    let shape = Capsule { a: Vector3::new(0f32, -1f32, 0f32), b: Vector3::new(0f32, 1f32, 0f32), radius: 0.5f32 };
+
+    assert_surface(&shape,
+        &[Vector3::new(0.5f32, 0f32, 0f32), Vector3::new(0f32, 1.5f32, 0f32), Vector3::new(0f32, -1f32, -0.5f32)],
+        &[Vector3::new(1f32, 0f32, 0f32), Vector3::new(0f32, -2f32, 0f32)]
+    );
+}
+
+#[test]
+fn test_cylinder() {
+    let shape = Cylinder { half_height: 1f32, radius: 0.5f32 };
+
+    assert_surface(&shape,
+        &[Vector3::new(0.5f32, 0f32, 0f32), Vector3::new(0f32, 1f32, 0.25f32), Vector3::new(0f32, -0.5f32, -0.5f32)],
+        &[Vector3::new(0.6f32, 0f32, 0f32), Vector3::new(0f32, 1.1f32, 0f32), Vector3::new(0.5f32, 1f32, 0.5f32)]
+    );
+}
+
+#[test]
+fn test_transformed_translate() {
+    let offset = Vector3::new(1f32, 2f32, 3f32);
+    let shape = Transformed::new(Sphere { radius: 0.5f32 }, Matrix4x4::translate(offset));
+
+    assert_surface(&shape,
+        &[offset + Vector3::new(0.5f32, 0f32, 0f32), offset - Vector3::new(0f32, 0f32, 0.5f32)],
+        &[Vector3::new(0.5f32, 0f32, 0f32)]
+    );
+
+    assert!((shape.distance(offset) + 0.5f32).abs() < 0.0001f32);
+}
+
+#[test]
+fn test_transformed_scale() {
+    let mut scale = Matrix4x4::identity();
+
+    for c in 0 .. 3 {
+        scale[c][c] = 2f32;
+    }
+
+    let shape = Transformed::new(Sphere { radius: 0.5f32 }, scale);
+
+    assert!(shape.distance(Vector3::new(1f32, 0f32, 0f32)).abs() < 0.0001f32);
+    assert!((shape.distance(Vector3::new(3f32, 0f32, 0f32)) - 2f32).abs() < 0.0001f32);
+}
+
+#[test]
+fn test_sphere_analytic_normal() {
+    let shape = Sphere { radius: 1.5f32 };
+    let sampled = |p: Vector3| shape.distance(p);
+
+    let points = [
+        Vector3::new(1.5f32, 0f32, 0f32),
+        Vector3::new(0f32, -1.5f32, 0f32),
+        Vector3::new(0f32, 0f32, 1.5f32),
+        Vector3::new(1f32, 1f32, 1f32).normalize() * 1.5f32,
+        Vector3::new(-2f32, 1f32, 0.5f32).normalize() * 1.5f32,
+    ];
+
+    for p in points {
+        let analytic = shape.normal(p);
+        let estimated = sampled.normal(p);
+
+        assert!((analytic - estimated).magnitude() < 0.01f32, "{} vs {} at {}", analytic, estimated, p);
+    }
+}
+
+#[test]
+fn test_smooth_min_sharpens() {
+    for (a, b) in [(0.3f32, 0.5f32), (-1f32, 0.2f32), (0.25f32, 0.25f32)] {
+        assert!((smooth_min(a, b, 0.0001f32) - a.min(b)).abs() < 0.0001f32);
+        assert!((smooth_min_quadratic(a, b, 0.0001f32) - a.min(b)).abs() < 0.0001f32);
+        assert_eq!(smooth_min(a, b, 0f32), a.min(b));
+    }
+}
+
+#[test]
+fn test_smooth_min_continuous() {
+    let k = 0.5f32;
+    let e = 0.0001f32;
+
+    // Across a == b, where min switches sides
+    assert!((smooth_min(1f32, 1f32 + e, k) - smooth_min(1f32 + e, 1f32, k)).abs() < 0.001f32);
+    assert!((smooth_min(1f32, 1f32 - e, k) - smooth_min(1f32, 1f32 + e, k)).abs() < 0.001f32);
+
+    // Across the edge of the blend region
+    assert!((smooth_min(1f32, 1f32 + k - e, k) - smooth_min(1f32, 1f32 + k + e, k)).abs() < 0.001f32);
+
+    // Blending only ever pulls the surface outwards
+    assert!(smooth_min(1f32, 1.1f32, k) < 1f32);
+}
+
+#[test]
+fn test_smooth_union() {
+    let shape = SmoothUnion {
+        a: Sphere { radius: 1f32 },
+        b: Transformed::new(Sphere { radius: 1f32 }, Matrix4x4::translate(Vector3::new(1.5f32, 0f32, 0f32))),
+        k: 0.5f32,
+        kind: SmoothKind::Cubic,
+    };
+
+    // Far from the seam it's just the closest sphere
+    assert!((shape.distance(Vector3::new(-2f32, 0f32, 0f32)) - 1f32).abs() < 0.0001f32);
+
+    // Between the spheres the gap gets filled in
+    let p = Vector3::new(0.75f32, 0.9f32, 0f32);
+    assert!(shape.distance(p) < Sphere { radius: 1f32 }.distance(p));
+}
+
+#[test]
+fn test_union() {
+    struct Tagged<S: Sdf>(S, u32);
+
+    impl<S: Sdf> Sdf for Tagged<S> {
+        fn distance(&self, p: Vector3) -> f32 {
+            self.0.distance(p)
+        }
+
+        fn material(&self, _p: Vector3) -> u32 {
+            self.1
+        }
+    }
+
+    let sphere = Tagged(Sphere { radius: 0.5f32 }, 1);
+    let torus = Tagged(Transformed::new(Torus { major_radius: 1f32, minor_radius: 0.25f32 }, Matrix4x4::translate(Vector3::new(3f32, 0f32, 0f32))), 2);
+
+    let scene = Union { a: sphere, b: torus };
+    let scene: &dyn Sdf = &scene;
+
+    assert!((scene.distance(Vector3::new(-1f32, 0f32, 0f32)) - 0.5f32).abs() < 0.0001f32);
+    assert!((scene.distance(Vector3::new(4f32, 0.5f32, 0f32)) - 0.25f32).abs() < 0.0001f32);
+
+    assert_eq!(scene.material(Vector3::new(0f32, 0.6f32, 0f32)), 1);
+    assert_eq!(scene.material(Vector3::new(4.2f32, 0f32, 0f32)), 2);
+}
+
+#[test]
+fn test_fold() {
+    // Only exists on the positive side of the X plane
+    let half = Transformed::new(Sphere { radius: 1f32 }, Matrix4x4::translate(Vector3::new(2f32, 0f32, 0f32)));
+    let mirrored = |p: Vector3| half.distance(fold_axis(p, 0));
+
+    for p in [Vector3::new(2f32, 0.5f32, 0f32), Vector3::new(1f32, 0f32, 0f32), Vector3::new(3f32, 1f32, -1f32)] {
+        let q = Vector3::new(-p[0], p[1], p[2]);
+
+        assert!((mirrored(p) - mirrored(q)).abs() < 0.0001f32);
+        assert!((mirrored(p) - half.distance(p)).abs() < 0.0001f32);
+    }
+
+    assert!(mirrored(Vector3::new(-2f32, 0f32, 0f32)) < 0f32);
+    assert!(half.distance(Vector3::new(-2f32, 0f32, 0f32)) > 0f32);
+
+    // An axis aligned plane folds the same way
+    let n = Vector3::new(1f32, 0f32, 0f32);
+    let p = Vector3::new(-3f32, 1f32, 2f32);
+    assert_eq!(fold_plane(p, n), fold_axis(p, 0));
+
+    // Points already on the positive side are left alone
+    let n = Vector3::new(1f32, 1f32, 0f32).normalize();
+    assert_eq!(fold_plane(Vector3::new(1f32, 0f32, 0f32), n), Vector3::new(1f32, 0f32, 0f32));
+
+    let folded = fold_plane(Vector3::new(-1f32, 0f32, 0f32), n);
+    assert!((folded - Vector3::new(0f32, 1f32, 0f32)).magnitude() < 0.0001f32);
+}
@@ -0,0 +1,48 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::rendering::shading::*;
+
+#[test]
+fn test_interpolate_attribute_corners() {
+    let a = Vector3::new(1f32, 0f32, 0f32);
+    let b = Vector3::new(0f32, 1f32, 0f32);
+    let c = Vector3::new(0f32, 0f32, 1f32);
+
+    assert_eq!(interpolate_attribute(0f32, 0f32, a, b, c), a);
+    assert_eq!(interpolate_attribute(1f32, 0f32, a, b, c), b);
+    assert_eq!(interpolate_attribute(0f32, 1f32, a, b, c), c);
+
+    let center = interpolate_attribute(1f32 / 3f32, 1f32 / 3f32, a, b, c);
+    assert!((center - Vector3::from_single(1f32 / 3f32)).magnitude() < 0.0001f32);
+}
+
+#[test]
+fn test_fresnel_normal_incidence() {
+    let (n1, n2) = (1f32, 1.5f32);
+
+    let incident = Vector3::new(0f32, -1f32, 0f32);
+    let normal = Vector3::new(0f32, 1f32, 0f32);
+
+    let f = fresnel(incident, normal, n1 / n2);
+
+    let expected = ((n1 - n2) / (n1 + n2)).powi(2);
+    assert!((f.reflectance - expected).abs() < 0.0001f32);
+
+    assert!((f.reflected - normal).magnitude() < 0.0001f32);
+    assert!((f.refracted - incident).magnitude() < 0.0001f32);
+}
+
+#[test]
+fn test_fresnel_grazing() {
+    let normal = Vector3::new(0f32, 1f32, 0f32);
+
+    // Nearly everything bounces off at grazing angles
+    let grazing = Vector3::new(1f32, -0.01f32, 0f32).normalize();
+    assert!(fresnel(grazing, normal, 1f32 / 1.5f32).reflectance > 0.9f32);
+
+    // Leaving glass at a shallow angle is totally internally reflected
+    let f = fresnel(Vector3::new(1f32, -0.5f32, 0f32).normalize(), normal, 1.5f32);
+    assert_eq!(f.reflectance, 1f32);
+    assert_eq!(f.refracted, Vector3::default());
+}
@@ -0,0 +1,80 @@
+#![allow(unused)]
+
+use crate::math::vector::common::*;
+use crate::math::matrix::common::Matrix4x4;
+use crate::rendering::viewport::*;
+use crate::rendering::tests::test_viewport;
+
+#[test]
+fn test_world_to_screen_round_trip() {
+    let viewport = test_viewport();
+    let size = Vector2::new(640f32, 480f32);
+
+    let point = Vector3::new(1.2f32, 1.5f32, 0f32);
+    let pixel = viewport.world_to_screen(point, size).unwrap();
+
+    let ray = viewport.screen_to_world_ray(pixel, size);
+
+    // Distance from the point to the line of the ray
+    let to_point = point - ray.origin;
+    let along = ray.direction * to_point.dot(ray.direction);
+
+    assert!((to_point - along).magnitude() < 0.001f32);
+    assert!(to_point.dot(ray.direction) > 0f32);
+}
+
+#[test]
+fn test_screen_center_ray() {
+    let viewport = Viewport { position: Vector3::new(0f32, 0f32, 5f32), ..Viewport::default() };
+    let ray = viewport.screen_to_world_ray(Vector2::new(50f32, 50f32), Vector2::new(100f32, 100f32));
+
+    assert!((ray.direction - Vector3::new(0f32, 0f32, -1f32)).magnitude() < 0.0001f32);
+}
+
+#[test]
+fn test_world_to_screen_behind() {
+    let viewport = Viewport { position: Vector3::new(0f32, 0f32, 5f32), ..Viewport::default() };
+
+    assert!(viewport.world_to_screen(Vector3::new(0f32, 0f32, 10f32), Vector2::new(100f32, 100f32)).is_none());
+}
+
+#[test]
+fn test_projection_aspect() {
+    let viewport = test_viewport();
+
+    let square = viewport.projection_matrix(1f32);
+    let wide = viewport.projection_matrix(2f32);
+
+    assert!((square[0][0] / wide[0][0] - 2f32).abs() < 0.0001f32);
+    assert_eq!(square[1][1], wide[1][1]);
+}
+
+#[test]
+fn test_linearize_depth() {
+    let (near, far) = (0.5f32, 40f32);
+    let projection = Matrix4x4::perspective(1f32, 1.5f32, near, far);
+
+    for depth in [near, 3f32, far] {
+        let clip = projection * Vector4::new(0.2f32, -0.1f32, -depth, 1f32);
+        let ndc_z = clip[2] / clip[3];
+
+        assert!((linearize_depth(ndc_z, near, far) - depth).abs() < 0.001f32, "{} at depth {}", ndc_z, depth);
+    }
+
+    assert_eq!(linearize_depth(-1f32, near, far), near);
+    assert_eq!(linearize_depth(1f32, near, far), far);
+}
+
+#[test]
+fn test_viewport_validate() {
+    assert!(test_viewport().validate().is_ok());
+
+    let inverted = Viewport { near_plane: 10f32, far_plane: 1f32, ..test_viewport() };
+    assert!(inverted.validate().is_err());
+
+    let behind = Viewport { near_plane: 0f32, ..test_viewport() };
+    assert!(behind.validate().is_err());
+
+    let wide = Viewport { field_of_view: 200f32.to_radians(), ..test_viewport() };
+    assert!(wide.validate().is_err());
+}
@@ -1,5 +1,8 @@
-use crate::math::vector::common::Vector3;
+use crate::math::vector::common::*;
+use crate::math::ray::Ray3D;
+use crate::math::matrix::common::Matrix4x4;
 
+#[derive(Clone, Copy, Debug)]
 pub struct Viewport {
     pub position: Vector3,
     pub euler: Vector3,
@@ -7,4 +10,87 @@ pub struct Viewport {
     pub field_of_view: f32,
     pub near_plane: f32,
     pub far_plane: f32
-}
\ No newline at end of file
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self {
+            position: Vector3::default(),
+            euler: Vector3::default(),
+
+            field_of_view: 30f32.to_radians(),
+            near_plane: 0.1f32,
+            far_plane: 100f32
+        }
+    }
+}
+
+impl Viewport {
+    /// Checks the projection parameters make sense, returning what's wrong with them otherwise
+    #[allow(dead_code)]
+    pub fn validate(&self) -> Result<(), String> {
+        if self.near_plane <= 0f32 {
+            return Err(format!("near_plane must be positive, got {}", self.near_plane));
+        }
+
+        if self.near_plane >= self.far_plane {
+            return Err(format!("near_plane ({}) must be closer than far_plane ({})", self.near_plane, self.far_plane));
+        }
+
+        if self.field_of_view <= 0f32 || self.field_of_view >= std::f32::consts::PI {
+            return Err(format!("field_of_view must be between 0 and PI radians, got {}", self.field_of_view));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the world to view matrix of this [Viewport]
+    pub fn view_matrix(&self) -> Matrix4x4 {
+        (Matrix4x4::translate(self.position) * Matrix4x4::rotation(self.euler)).inverse()
+    }
+
+    /// Returns the perspective projection matrix of this [Viewport] for a render target with the given aspect ratio (width / height)
+    pub fn projection_matrix(&self, aspect: f32) -> Matrix4x4 {
+        Matrix4x4::perspective(self.field_of_view, aspect, self.near_plane, self.far_plane)
+    }
+
+    /// Returns the ray leaving the camera through the given pixel
+    ///
+    /// `pixel` ranges from `(0, 0)` in the top left to `size` in the bottom right
+    #[allow(dead_code)]
+    pub fn screen_to_world_ray(&self, pixel: Vector2, size: Vector2) -> Ray3D {
+        let ndc_x = pixel[0] / size[0] * 2f32 - 1f32;
+        let ndc_y = 1f32 - pixel[1] / size[1] * 2f32;
+
+        let mat_vp_i = (self.projection_matrix(size[0] / size[1]) * self.view_matrix()).inverse();
+
+        let point = mat_vp_i.unproject(Vector3::new(ndc_x, ndc_y, 0f32));
+
+        Ray3D::new(self.position, (point - self.position).normalize())
+    }
+
+    /// Returns the pixel the given world space point lands on (see [Viewport::screen_to_world_ray])
+    ///
+    /// Returns [None] if the point is behind the camera
+    #[allow(dead_code)]
+    pub fn world_to_screen(&self, point: Vector3, size: Vector2) -> Option<Vector2> {
+        let clip = self.projection_matrix(size[0] / size[1]) * self.view_matrix() * Vector4::new(point[0], point[1], point[2], 1f32);
+
+        if clip[3] <= 0f32 {
+            return None;
+        }
+
+        let ndc_x = clip[0] / clip[3];
+        let ndc_y = clip[1] / clip[3];
+
+        Some(Vector2::new((ndc_x + 1f32) * 0.5f32 * size[0], (1f32 - ndc_y) * 0.5f32 * size[1]))
+    }
+}
+
+/// Recovers the view space distance in front of the camera from an NDC depth produced by [Matrix4x4::perspective]
+///
+/// `near` and `far` must match the clip planes the projection was built with, `-1` maps back to `near` and `1` to `far`
+#[allow(dead_code)]
+pub fn linearize_depth(ndc_z: f32, near: f32, far: f32) -> f32 {
+    (2f32 * far * near) / (far + near - ndc_z * (far - near))
+}